@@ -0,0 +1,113 @@
+//! Generates "inputs.rs" in `OUT_DIR`, which is included by "src/input.rs" and contains:
+//! - `MANIFEST`, every day that has an input file in "inputs".
+//! - `EMBEDDED`, the inputs that are embedded into the binary (only with the `embed-inputs` feature).
+//!
+//! Also warns about any day whose module exists (in an enabled year) but whose input is missing.
+
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let inputs_dir = root.join("inputs");
+    let src_dir = root.join("src");
+    // * Cargo would rerun this on every build if it watched a missing directory, so "inputs" is only watched once it exists
+    // * (it's then picked up as soon as the next change to "src" is built).
+    if inputs_dir.is_dir() {
+        println!("cargo:rerun-if-changed={}", inputs_dir.display());
+    }
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let inputs = scan(&inputs_dir, "txt");
+    let modules = scan(&src_dir, "rs");
+    let embed = env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some();
+
+    let mut manifest = String::new();
+    let mut embedded = String::new();
+    for &(year, day) in &inputs {
+        manifest.push_str(&format!("    ({}, {}),\n", year, day));
+        if embed && year_enabled(year) {
+            let path = input_path(&inputs_dir, year, day);
+            embedded.push_str(&format!(
+                "    ({}, {}, include_str!({:?})),\n",
+                year,
+                day,
+                path.display().to_string()
+            ));
+        }
+    }
+
+    let mut years = modules.iter().map(|&(year, _)| year).collect::<Vec<_>>();
+    years.dedup();
+    for year in years.into_iter().filter(|&y| year_enabled(y)) {
+        let missing = modules
+            .iter()
+            .filter(|&&(y, day)| y == year && !inputs.contains(&(y, day)))
+            .map(|(_, day)| day.to_string())
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            continue;
+        }
+        println!(
+            "cargo:warning=No input for {} day(s) {} (expected at '{}'), which will be skipped when several days are run{}.",
+            year,
+            missing.join(", "),
+            inputs_dir.join(format!("y{}", year)).join("dD.txt").display(),
+            if embed { " and aren't embedded" } else { "" }
+        );
+    }
+
+    let generated = format!(
+        "// * Generated by \"build.rs\".\n\n\
+         /// Every day with an input file in \"inputs\" when the crate was built, as (year, day).\n\
+         pub const MANIFEST: &[(u32, u32)] = &[\n{}];\n\n\
+         /// The inputs embedded into the binary, as (year, day, input).\n\
+         const EMBEDDED: &[(u32, u32, &str)] = &[\n{}];\n",
+        manifest, embedded
+    );
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("inputs.rs");
+    fs::write(&out, generated).unwrap();
+}
+
+/// Find every "yYYYY/dD.`ext`" in `dir`, sorted by year then day.
+fn scan(dir: &Path, ext: &str) -> BTreeSet<(u32, u32)> {
+    let mut found = BTreeSet::new();
+    for year_entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let name = year_entry.file_name();
+        let Some(year) = name
+            .to_str()
+            .and_then(|n| n.strip_prefix('y')?.parse().ok())
+        else {
+            continue;
+        };
+        for day_entry in fs::read_dir(year_entry.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let name = day_entry.file_name();
+            let day = name
+                .to_str()
+                .and_then(|n| n.strip_prefix('d')?.strip_suffix(ext)?.strip_suffix('.'))
+                .and_then(|n| n.parse().ok());
+            if let Some(day) = day {
+                found.insert((year, day));
+            }
+        }
+    }
+    return found;
+}
+
+fn input_path(inputs_dir: &Path, year: u32, day: u32) -> PathBuf {
+    return inputs_dir
+        .join(format!("y{}", year))
+        .join(format!("d{}.txt", day));
+}
+
+/// Whether the feature of `year` is enabled (see "Cargo.toml").
+fn year_enabled(year: u32) -> bool {
+    return env::var_os(format!("CARGO_FEATURE_Y{}", year)).is_some();
+}
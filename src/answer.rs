@@ -0,0 +1,103 @@
+use std::{fmt::Display, str::FromStr};
+
+/// The answer to one part of a puzzle, which is either a (possibly negative) integer or some text (e.g. "1,0,3,4").
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Text(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("Answer is empty!".to_string());
+        }
+        return Ok(Self::from(s));
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        // * Text is only treated as a number if it would be displayed identically (e.g. "007" and "+7" stay as text),
+        // * so that `Answer::from(n.to_string()) == Answer::from(n)` always holds.
+        match value.parse::<i128>() {
+            Ok(n) if n.to_string() == value => Self::Number(n),
+            _ => Self::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        return Self::from(value.as_str());
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    return Self::Number(value as i128);
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        assert_eq!("42".parse(), Ok(Answer::Number(42)));
+        assert_eq!(" -17\n".parse(), Ok(Answer::Number(-17)));
+        assert_eq!("1,0,3".parse(), Ok(Answer::Text("1,0,3".to_string())));
+        assert!("".parse::<Answer>().is_err());
+        assert!(" \n".parse::<Answer>().is_err());
+
+        // * Numbers that wouldn't be displayed the same way stay as text.
+        for text in ["007", "-0", "+7", "1e3"] {
+            let answer = Answer::from(text);
+            assert_eq!(answer, Answer::Text(text.to_string()));
+            assert_eq!(answer.to_string(), text);
+        }
+        for n in [0, 7, -7, i128::MAX, i128::MIN] {
+            assert_eq!(Answer::from(n.to_string()), Answer::Number(n));
+        }
+    }
+
+    #[test]
+    fn from_ints() {
+        assert_eq!(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128));
+        assert_eq!(Answer::from(i64::MIN), Answer::Number(i64::MIN as i128));
+        assert_eq!(Answer::from(-3i8), Answer::Number(-3));
+        assert_eq!(Answer::from(5usize), Answer::from("5"));
+        assert_eq!(Answer::from(u64::MAX).as_number(), Some(u64::MAX as i128));
+        assert_eq!(Answer::from("abc").as_number(), None);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{collections::BTreeMap, io, path::PathBuf, time::Duration};
+
+use crate::{
+    answer::Answer,
+    bench,
+    input::InputSource,
+    registry,
+    report::{self, Format, Record, Status},
+    runner::{self, Outcome, Selection},
+    solution::Part,
+};
+
+/// The directory containing every year's known answers, as "yYYYY.txt".
+pub const ANSWERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers");
+
+/// The known (i.e. accepted) answers to a year's puzzles.
+/// Stored as lines of "day D, part P: ANSWER", the same format that solutions are printed in,
+/// where empty lines and lines starting with '#' are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    known: BTreeMap<(u32, Part), Answer>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut known = BTreeMap::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || format!("Invalid answer on line {}: '{}'!", i + 1, line);
+
+            let (key, answer) = line.split_once(':').ok_or_else(invalid)?;
+            let (day, part) = parse_key(key).ok_or_else(invalid)?;
+            known.insert((day, part), answer.parse()?);
+        }
+        return Ok(Self { known });
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&Answer> {
+        return self.known.get(&(day, part));
+    }
+}
+
+/// Parse the "day D, part P" that each line of the answers file (as well as the baselines and guesses files) starts with.
+pub fn parse_key(key: &str) -> Option<(u32, Part)> {
+    let (day, part) = key.strip_prefix("day ")?.split_once(", part ")?;
+    let day = day.parse().ok()?;
+    let part = Part::from_number(part.parse().ok()?)?;
+    return Some((day, part));
+}
+
+/// Append an accepted answer to the answers file of `year`, creating it if needed.
+pub fn record(year: u32, day: u32, part: Part, answer: &Answer) -> Result<(), String> {
+    let path = path(year);
+    let failed = |err: io::Error| format!("Failed to write '{}': {}", path.display(), err);
+    let mut contents = match std::fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => format!(
+            "# The accepted answers to {}'s puzzles, checked by `advent-of-code verify`.\n",
+            year
+        ),
+        Err(err) => return Err(failed(err)),
+    };
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&format!("day {}, part {}: {}\n", day, part, answer));
+    return std::fs::create_dir_all(ANSWERS_DIR)
+        .and_then(|_| std::fs::write(&path, contents))
+        .map_err(failed);
+}
+
+pub fn path(year: u32) -> PathBuf {
+    return PathBuf::from(ANSWERS_DIR).join(format!("y{}.txt", year));
+}
+
+/// Load the known answers of `year`, which are empty if that year has no answers file.
+pub fn load(year: u32) -> Result<Answers, String> {
+    let path = path(year);
+    match std::fs::read_to_string(&path) {
+        Ok(s) => Answers::parse(&s).map_err(|err| format!("{} ({})", err, path.display())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(err) => Err(format!("Failed to read '{}': {}", path.display(), err)),
+    }
+}
+
+/// Run the selected solutions (or every solution if there's no selection) on their inputs and check them against the known answers,
+/// printing their results as `format` and failing if any of them didn't match.
+pub fn verify(
+    selection: Option<&Selection>,
+    threads: usize,
+    timeout: Option<Duration>,
+    format: Format,
+) -> Result<(), String> {
+    let mut answers = BTreeMap::new();
+    for year in registry::years() {
+        if selection.is_none_or(|s| s.year == year) {
+            answers.insert(year, load(year)?);
+        }
+    }
+    let solutions = registry::all().filter(|s| answers.contains_key(&s.year()));
+    // * Days without an input are still reported (as missing) rather than skipped, since their answers can't be checked.
+    let jobs = runner::jobs(solutions, selection, &InputSource::Default, false);
+    if jobs.is_empty() {
+        return Err(match selection {
+            Some(selection) => format!("No solutions found for {}!", selection),
+            None => "No solutions found!".to_string(),
+        });
+    }
+
+    let text = format == Format::Text;
+    let mut records = Vec::new();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut year = None;
+    // * A day's input error is only reported once, rather than for each of its parts.
+    let mut input_error_day = None;
+    runner::run_jobs(&jobs, threads, timeout, |res| {
+        let (day, part) = (res.day, res.part);
+        let expected = answers[&res.year].get(day, part);
+        let status = Status::verify(&res.outcome, expected);
+        match status {
+            Status::Pass => passed += 1,
+            Status::Fail => failed += 1,
+            _ => missing += 1,
+        }
+
+        if text {
+            if year.replace(res.year) != Some(res.year) {
+                println!("{} Solutions", res.year);
+            }
+            let line = match (&res.outcome, expected) {
+                (Outcome::InputError(err), _) => {
+                    let first = input_error_day.replace((res.year, day)) != Some((res.year, day));
+                    first.then(|| format!("  day {}: missing ({})", day, err))
+                }
+                (Outcome::Todo, _) => Some(format!("  day {}, part {}: missing (TODO)", day, part)),
+                (Outcome::Solved(_), Some(_)) if status == Status::Pass => Some(format!(
+                    "  day {}, part {}: pass ({})",
+                    day,
+                    part,
+                    bench::fmt_duration(res.duration)
+                )),
+                (Outcome::Solved(answer), Some(expected)) => Some(format!(
+                    "  day {}, part {}: FAIL (got {}, expected {})",
+                    day, part, answer, expected
+                )),
+                (Outcome::Solved(answer), None) => Some(format!(
+                    "  day {}, part {}: missing answer (got {})",
+                    day, part, answer
+                )),
+                (outcome, _) => Some(format!("  day {}, part {}: FAIL ({})", day, part, outcome)),
+            };
+            if let Some(line) = line {
+                println!("{}", line);
+            }
+        }
+        records.push(Record::new(&res, status));
+    });
+
+    if text {
+        println!(
+            "\n{} passed, {} failed, {} missing",
+            passed, failed, missing
+        );
+    } else {
+        println!("{}", report::render(format, &records));
+    }
+    if failed > 0 {
+        return Err(format!(
+            "{} part(s) didn't match their known answer!",
+            failed
+        ));
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers =
+            Answers::parse("# 2024\n\nday 9, part 2: 6547228115826\nday 17, part 1: 1,0,3\n")
+                .unwrap();
+        assert_eq!(
+            answers.get(9, Part::Two),
+            Some(&Answer::Number(6547228115826))
+        );
+        assert_eq!(
+            answers.get(17, Part::One),
+            Some(&Answer::Text("1,0,3".to_string()))
+        );
+        assert_eq!(answers.get(9, Part::One), None);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Answers::parse("day 9, part 3: 12").is_err());
+        assert!(Answers::parse("day 9: 12").is_err());
+        assert!(Answers::parse("day 9, part 1:").is_err());
+        assert!(Answers::parse("day x, part 1: 12").is_err());
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(parse_key("day 25, part 2"), Some((25, Part::Two)));
+        assert_eq!(parse_key("day 25, part 0"), None);
+        assert_eq!(parse_key("day -1, part 1"), None);
+        assert_eq!(parse_key("day 1 part 1"), None);
+    }
+}
@@ -0,0 +1,418 @@
+use std::{
+    collections::BTreeMap,
+    hint::black_box,
+    io,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers,
+    input::{self, InputSource},
+    registry,
+    runner::{self, Outcome, Selection},
+    solution::Part,
+};
+
+/// The number of times each part is run when benchmarking, unless `--runs` is given.
+pub const DEFAULT_RUNS: u32 = 10;
+/// How much slower (in percent) than its baseline a part can get before it's flagged, unless `--threshold` is given.
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+/// The directory containing every year's timing baseline, as "yYYYY.txt".
+/// These depend on the machine they were measured on, so they aren't committed.
+pub const BASELINES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/baselines");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// How many times each part is run.
+    pub runs: u32,
+    /// The percentage by which a part's median time can exceed its baseline before it's flagged as a regression.
+    pub threshold: u32,
+    pub save_baseline: bool,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        return Self {
+            runs: DEFAULT_RUNS,
+            threshold: DEFAULT_THRESHOLD,
+            save_baseline: false,
+        };
+    }
+}
+
+/// Timing statistics of a repeatedly measured piece of code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Calculate the statistics of (a non-empty list of) `samples`.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+        return Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / len as u32,
+        };
+    }
+}
+
+/// Run `f` `runs` times (at least once), returning the result of its last run along with its timing statistics,
+/// or the first error it returns (after which it isn't run again).
+pub fn measure<T, E>(runs: u32, mut f: impl FnMut() -> Result<T, E>) -> Result<(T, Stats), E> {
+    let mut samples = Vec::with_capacity(runs as usize);
+    let mut res = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let out = black_box(f())?;
+        samples.push(start.elapsed());
+        res = Some(out);
+    }
+    return Ok((res.unwrap(), Stats::from_samples(&mut samples)));
+}
+
+/// The timing of one part of a day.
+/// `read` is the time taken to read the day's input, while `solve` is the time taken by the part's solver.
+/// Solvers are given the raw input, so `solve` includes parsing it, which is also timed on its own as `parse`
+/// if the day has a parse step (see `Solution::parser`).
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub day: u32,
+    pub part: Part,
+    pub read: Stats,
+    pub parse: Option<Stats>,
+    pub solve: Stats,
+}
+
+impl Timing {
+    /// The median time the part's solver spent after parsing the input, if the day's parsing was timed.
+    pub fn after_parse(&self) -> Option<Duration> {
+        return Some(self.solve.median.saturating_sub(self.parse?.median));
+    }
+}
+
+/// The median solve times of a year's parts from a previous benchmark, which later benchmarks are compared against.
+/// Stored as lines of "day D, part P: NANOSECONDS", where empty lines and lines starting with '#' are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, Part), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut medians = BTreeMap::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || format!("Invalid baseline on line {}: '{}'!", i + 1, line);
+
+            let (key, nanos) = line.split_once(':').ok_or_else(invalid)?;
+            let (day, part) = answers::parse_key(key).ok_or_else(invalid)?;
+            let nanos = nanos.trim().parse().map_err(|_| invalid())?;
+            medians.insert((day, part), Duration::from_nanos(nanos));
+        }
+        return Ok(Self { medians });
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<Duration> {
+        return self.medians.get(&(day, part)).copied();
+    }
+
+    /// Replace the baselines of the parts in `timings`, keeping those of any other parts.
+    pub fn update(&mut self, timings: &[Timing]) {
+        for t in timings {
+            self.medians.insert((t.day, t.part), t.solve.median);
+        }
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "# Median solve times in nanoseconds, saved by `advent-of-code bench --save-baseline`."
+        )?;
+        for ((day, part), median) in &self.medians {
+            writeln!(f, "day {}, part {}: {}", day, part, median.as_nanos())?;
+        }
+        return Ok(());
+    }
+}
+
+pub fn baseline_path(year: u32) -> PathBuf {
+    return PathBuf::from(BASELINES_DIR).join(format!("y{}.txt", year));
+}
+
+/// Load the timing baseline of `year`, which is empty if it hasn't been saved.
+pub fn load_baseline(year: u32) -> Result<Baseline, String> {
+    let path = baseline_path(year);
+    match std::fs::read_to_string(&path) {
+        Ok(s) => Baseline::parse(&s).map_err(|err| format!("{} ({})", err, path.display())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+        Err(err) => Err(format!("Failed to read '{}': {}", path.display(), err)),
+    }
+}
+
+pub fn save_baseline(year: u32, baseline: &Baseline) -> Result<(), String> {
+    let path = baseline_path(year);
+    return std::fs::create_dir_all(BASELINES_DIR)
+        .and_then(|_| std::fs::write(&path, baseline.to_string()))
+        .map_err(|err| format!("Failed to write '{}': {}", path.display(), err));
+}
+
+/// How much slower (in percent) `median` is than `baseline`, which is negative if it's quicker.
+pub fn change(median: Duration, baseline: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    return (median.as_secs_f64() - baseline) / baseline * 100.0;
+}
+
+/// Print `timings` as a table, sorted from slowest to quickest (median) solve time,
+/// comparing them against `baseline` and returning how many parts were more than `threshold` percent slower.
+pub fn print_table(
+    year: u32,
+    runs: u32,
+    timings: &mut [Timing],
+    baseline: &Baseline,
+    threshold: u32,
+) -> usize {
+    timings.sort_by_key(|t| std::cmp::Reverse(t.solve.median));
+
+    println!("{} Benchmark ({} runs, slowest first)", year, runs);
+    println!(
+        "  {:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "day", "part", "read", "parse", "solve", "min", "median", "mean", "baseline", "change"
+    );
+    let mut regressions = 0;
+    for t in timings.iter() {
+        let base = baseline.get(t.day, t.part);
+        let delta = base.map(|base| change(t.solve.median, base));
+        let slower = delta.is_some_and(|delta| delta > threshold as f64);
+        if slower {
+            regressions += 1;
+        }
+        println!(
+            "  {:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}{}",
+            t.day,
+            t.part.number(),
+            fmt_duration(t.read.median),
+            t.parse
+                .map_or("-".to_string(), |parse| fmt_duration(parse.median)),
+            t.after_parse().map_or("-".to_string(), fmt_duration),
+            fmt_duration(t.solve.min),
+            fmt_duration(t.solve.median),
+            fmt_duration(t.solve.mean),
+            base.map_or("-".to_string(), fmt_duration),
+            delta.map_or("-".to_string(), |delta| format!("{:+.1}%", delta)),
+            if slower { "  SLOWER" } else { "" }
+        );
+    }
+    return regressions;
+}
+
+/// Time the selected parts (on their inputs from `source`) and print their timings,
+/// failing if any of them got slower than their baseline (unless the timings are saved as the new baseline).
+pub fn run(
+    selection: &Selection,
+    source: &InputSource,
+    options: BenchOptions,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let runs = options.runs;
+    let mut baseline = load_baseline(selection.year)?;
+    // * Stdin can only be read once.
+    let read_runs = if *source == InputSource::Stdin {
+        1
+    } else {
+        runs
+    };
+    let mut timings = Vec::new();
+    println!("{} Solutions", selection.year);
+    for solution in registry::year(selection.year) {
+        let parts = runner::selected_parts(solution, Some(selection));
+        // * As when running, days without an input are only skipped when several days are selected.
+        let skip = !selection.is_single_day() && !runner::has_input(solution, source);
+        if parts.is_empty() || skip {
+            continue;
+        }
+        let (input, read_time) = match measure(read_runs, || input::load(solution, source)) {
+            Ok((input, read_time)) => (Arc::<str>::from(input), read_time),
+            Err(err) => {
+                println!("  day {}: {}", solution.day(), err);
+                continue;
+            }
+        };
+        let solved = timings.len();
+        for part in parts {
+            let Some(solver) = solution.solver(part) else {
+                continue;
+            };
+            // * Every run is protected like any other (so with a timeout, each run includes starting its thread),
+            // * and a part stops being timed as soon as a run doesn't produce an answer.
+            let measured = measure(runs, || {
+                match runner::execute_with_timeout(solver, &input, timeout) {
+                    Outcome::Solved(answer) => Ok(answer),
+                    outcome => Err(outcome),
+                }
+            });
+            let (answer, solve_time) = match measured {
+                Ok(measured) => measured,
+                Err(outcome) => {
+                    println!("  day {}, part {}: {}", solution.day(), part, outcome);
+                    continue;
+                }
+            };
+            println!(
+                "  day {}, part {}: {} ({})",
+                solution.day(),
+                part,
+                answer,
+                fmt_duration(solve_time.median)
+            );
+            timings.push(Timing {
+                day: solution.day(),
+                part,
+                read: read_time,
+                parse: None,
+                solve: solve_time,
+            });
+        }
+        // * The parse step is only timed once a part has been solved, since its solver (which parses the input the
+        // * same way) is then known to finish. As it also can't produce an error, it's run unprotected.
+        if let (Some(parser), true) = (solution.parser(), timings.len() > solved) {
+            let parse_time = measure(runs, || {
+                parser(&input);
+                return Ok::<_, ()>(());
+            })
+            .ok()
+            .map(|(_, parse_time)| parse_time);
+            for t in &mut timings[solved..] {
+                t.parse = parse_time;
+            }
+        }
+    }
+    if timings.is_empty() {
+        return Err(format!(
+            "No solutions with an input found for {}!",
+            selection
+        ));
+    }
+    println!();
+    let regressions = print_table(
+        selection.year,
+        runs,
+        &mut timings,
+        &baseline,
+        options.threshold,
+    );
+
+    if options.save_baseline {
+        baseline.update(&timings);
+        save_baseline(selection.year, &baseline)?;
+        println!(
+            "\nSaved baseline to '{}'.",
+            baseline_path(selection.year).display()
+        );
+    } else if regressions > 0 {
+        return Err(format!(
+            "\n{} part(s) got more than {}% slower than the baseline!",
+            regressions, options.threshold
+        ));
+    }
+    return Ok(());
+}
+
+pub fn fmt_duration(duration: Duration) -> String {
+    return format!("{:.2?}", duration);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(&mut [ms(4), ms(1), ms(10), ms(5)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.mean, ms(5));
+
+        let stats = Stats::from_samples(&mut [ms(3), ms(2), ms(7)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(4));
+    }
+
+    #[test]
+    fn measuring() {
+        let (out, stats) = measure(3, || Ok::<_, ()>(7)).unwrap();
+        assert_eq!(out, 7);
+        assert!(stats.min <= stats.median);
+
+        // * Measuring stops at the first error.
+        let mut runs = 0;
+        let res = measure(5, || {
+            runs += 1;
+            return if runs == 2 { Err(runs) } else { Ok(()) };
+        });
+        assert_eq!(res, Err(2));
+        assert_eq!(runs, 2);
+    }
+
+    #[test]
+    fn baseline() {
+        let mut baseline = Baseline::parse("# Comment\nday 20, part 1: 1500000\n").unwrap();
+        assert_eq!(
+            baseline.get(20, Part::One),
+            Some(Duration::from_micros(1500))
+        );
+
+        let stats = Stats::from_samples(&mut [Duration::from_millis(3)]);
+        baseline.update(&[Timing {
+            day: 22,
+            part: Part::Two,
+            read: stats,
+            parse: None,
+            solve: stats,
+        }]);
+        assert_eq!(Baseline::parse(&baseline.to_string()), Ok(baseline));
+        assert_eq!(
+            change(Duration::from_millis(3), Duration::from_millis(2)),
+            50.0
+        );
+    }
+
+    #[test]
+    fn after_parse() {
+        let stats = |millis| Stats::from_samples(&mut [Duration::from_millis(millis)]);
+        let mut timing = Timing {
+            day: 1,
+            part: Part::One,
+            read: stats(1),
+            parse: None,
+            solve: stats(5),
+        };
+        assert_eq!(timing.after_parse(), None);
+        timing.parse = Some(stats(2));
+        assert_eq!(timing.after_parse(), Some(Duration::from_millis(3)));
+        // * Parsing on its own can measure slower than the whole solver.
+        timing.parse = Some(stats(6));
+        assert_eq!(timing.after_parse(), Some(Duration::ZERO));
+    }
+}
@@ -0,0 +1,456 @@
+use std::{ops::RangeInclusive, path::PathBuf, time::Duration};
+
+use advent_of_code::{
+    bench::BenchOptions, input::InputSource, report::Format, runner::Selection, solution::Part,
+};
+
+pub const USAGE: &str = "\
+Usage: advent-of-code <YEAR> [DAYS] [PART] [--input <PATH>] [--timeout <SECONDS>] [--jobs <N>] [--format <FORMAT>]
+       advent-of-code --list [YEAR]
+       advent-of-code verify [YEAR] [DAYS] [PART] [--timeout <SECONDS>] [--jobs <N>] [--format <FORMAT>]
+       advent-of-code bench <YEAR> [DAYS] [PART] [--input <PATH>] [--runs <N>] [--threshold <PERCENT>] [--save-baseline]
+       advent-of-code new <YEAR> <DAY> [TITLE]
+       advent-of-code fetch <YEAR> <DAY>
+       advent-of-code submit <YEAR> <DAY> <PART> [--input <PATH>] [--timeout <SECONDS>]
+       advent-of-code watch <YEAR> <DAY> [PART] [--example <PATH>] [--timeout <SECONDS>]
+
+Commands:
+  verify  Run the selected solutions (or every solution if YEAR is omitted) on their inputs,
+          and check them against the known answers in \"answers/yYYYY.txt\".
+  bench   Time the selected solutions over several runs, printing a table of their timings
+          (including how long parsing took, for days with a separate parse step).
+          Timings are compared against the baseline in \"baselines/yYYYY.txt\" if it exists.
+  new     Create \"src/yYYYY/dD.rs\" from a template and register it (creating the year's module
+          if needed), so that it can be run once the crate is rebuilt.
+  fetch   Download a day's input to \"inputs/yYYYY/dD.txt\", unless that file already exists.
+          Requires the session token (the site's \"session\" cookie) in AOC_SESSION or \".session\".
+          Requests are sent over plain HTTP to AOC_BASE_URL (default \"http://adventofcode.com\"),
+          through the proxy at AOC_PROXY (\"host:port\") if set, which must handle TLS for the real site.
+  submit  Run a part on its input and submit the answer (configured like 'fetch'), logging the guess
+          and its verdict to \"guesses/yYYYY.txt\". Correct answers are added to \"answers/yYYYY.txt\".
+  watch   Run a day whenever its input (or the example given by '--example') changes, printing
+          how each answer changed and how long it took. Inputs are read at runtime, but changes to
+          the day's source only print a reminder, since they require rebuilding.
+
+Arguments:
+  YEAR  The event's year (e.g. 2024).
+  DAYS  A single day (17), a range of days (1..10, 1..=10, 20..), or 'all' (default).
+  PART  Either 1 or 2. Both parts are run if omitted.
+
+Options:
+  --input <PATH>  Read the (single) selected day's input from PATH, or stdin if PATH is '-',
+                  instead of \"inputs/yYYYY/dD.txt\".
+  --timeout <SECONDS>
+                  Give up on any part that takes longer than SECONDS (which may be fractional),
+                  reporting it as timed out and moving on to the next part.
+  --jobs <N>      Run up to N parts at once (default 1). Results are still printed in order.
+  --format <FORMAT>
+                  Print the results as 'text' (default), 'json', 'csv' or 'markdown', each containing
+                  the year, day, part, answer, duration and status of every part.
+  --example <PATH>
+                  Also watch and run the example input in PATH (when watching).
+  --list          List every available solution, optionally only for YEAR.
+  --runs <N>      How many times each part is run when benchmarking (default 10).
+  --threshold <PERCENT>
+                  How much slower than its baseline a part can get before it's flagged as a
+                  regression when benchmarking (default 10).
+  --save-baseline Save the benchmarked timings as the new baseline.
+  -h, --help      Print this message.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub input: InputSource,
+    /// How long a part's solver can run for before it's given up on.
+    pub timeout: Option<Duration>,
+    /// How many parts can be run at the same time.
+    pub jobs: usize,
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(Selection),
+    List(Option<u32>),
+    /// Check solutions against their known answers, optionally only those selected.
+    Verify(Option<Selection>),
+    /// Time the selected solutions.
+    Bench {
+        selection: Selection,
+        options: BenchOptions,
+    },
+    /// Create a new day's module from a template.
+    New {
+        year: u32,
+        day: u32,
+        title: String,
+    },
+    /// Download a day's input.
+    Fetch {
+        year: u32,
+        day: u32,
+    },
+    /// Submit the answer to a part.
+    Submit {
+        year: u32,
+        day: u32,
+        part: Part,
+    },
+    /// Re-run a day whenever its input (or an example input) changes.
+    Watch {
+        selection: Selection,
+        example: Option<PathBuf>,
+    },
+    Help,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let mut help = false;
+    let mut list = false;
+    let mut input = InputSource::Default;
+    let mut timeout = None;
+    let mut jobs = None;
+    let mut format = None;
+    let mut example = None;
+    let mut bench = BenchOptions::default();
+    // * The first option used that only applies to benchmarking.
+    let mut bench_flag = None;
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => help = true,
+            "--list" => list = true,
+            "--input" => {
+                input = match value(&mut args, &arg)?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::Path(path.into()),
+                }
+            }
+            "--timeout" => {
+                let value = value(&mut args, &arg)?;
+                timeout = match value
+                    .parse()
+                    .ok()
+                    .and_then(|s| Duration::try_from_secs_f64(s).ok())
+                {
+                    Some(timeout) if !timeout.is_zero() => Some(timeout),
+                    _ => return Err(format!("Invalid timeout '{}'!", value)),
+                };
+            }
+            "--jobs" => {
+                jobs = match parse_num(&value(&mut args, &arg)?, "number of jobs")? {
+                    0 => return Err("'--jobs' must be at least 1!".to_string()),
+                    n => Some(n as usize),
+                };
+            }
+            "--format" => format = Some(value(&mut args, &arg)?.parse()?),
+            "--example" => example = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--runs" => {
+                bench.runs = match parse_num(&value(&mut args, &arg)?, "number of runs")? {
+                    0 => return Err("'--runs' must be at least 1!".to_string()),
+                    n => n,
+                };
+                bench_flag.get_or_insert(arg);
+            }
+            "--threshold" => {
+                let value = value(&mut args, &arg)?;
+                bench.threshold = parse_num(value.trim_end_matches('%'), "threshold")?;
+                bench_flag.get_or_insert(arg);
+            }
+            "--save-baseline" => {
+                bench.save_baseline = true;
+                bench_flag.get_or_insert(arg);
+            }
+            s if s.starts_with('-') => return Err(format!("Unknown option '{}'!", s)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut command = if help {
+        Command::Help
+    } else {
+        parse_command(list, &positional, bench, bench_flag.as_deref())?
+    };
+    if let Some(path) = example {
+        match &mut command {
+            Command::Watch { example, .. } => *example = Some(path),
+            _ => return Err("'--example' can only be used when watching!".to_string()),
+        }
+    }
+    if input != InputSource::Default {
+        match &command {
+            Command::Run(selection) | Command::Bench { selection, .. }
+                if selection.is_single_day() => {}
+            Command::Submit { .. } => {}
+            Command::Run(_) | Command::Bench { .. } => {
+                return Err("'--input' can only be used when a single day is selected!".to_string())
+            }
+            _ => return Err("'--input' can only be used when running solutions!".to_string()),
+        }
+    }
+    if timeout.is_some()
+        && !matches!(
+            command,
+            Command::Run(_)
+                | Command::Verify(_)
+                | Command::Bench { .. }
+                | Command::Submit { .. }
+                | Command::Watch { .. }
+        )
+    {
+        return Err("'--timeout' can only be used when running solutions!".to_string());
+    }
+    if jobs.is_some() && !matches!(command, Command::Run(_) | Command::Verify(_)) {
+        // * Benchmarking parts at the same time would skew their timings.
+        return Err("'--jobs' can only be used when running or verifying solutions!".to_string());
+    }
+    if format.is_some() && !matches!(command, Command::Run(_) | Command::Verify(_)) {
+        return Err("'--format' can only be used when running or verifying solutions!".to_string());
+    }
+    return Ok(Args {
+        command,
+        input,
+        timeout,
+        jobs: jobs.unwrap_or(1),
+        format: format.unwrap_or_default(),
+    });
+}
+
+fn parse_command(
+    list: bool,
+    positional: &[String],
+    bench: BenchOptions,
+    bench_flag: Option<&str>,
+) -> Result<Command, String> {
+    if let Some(flag) = bench_flag {
+        if list || positional.first().is_none_or(|c| c != "bench") {
+            return Err(format!("'{}' can only be used when benchmarking!", flag));
+        }
+    }
+    if list {
+        return match positional {
+            [] => Ok(Command::List(None)),
+            [year] => Ok(Command::List(Some(parse_num(year, "year")?))),
+            _ => Err("'--list' only accepts a year!".to_string()),
+        };
+    }
+
+    return match positional {
+        [] => Ok(Command::Help),
+        [command, rest @ ..] if command == "verify" => match rest {
+            [] => Ok(Command::Verify(None)),
+            _ => Ok(Command::Verify(Some(parse_selection(rest)?))),
+        },
+        [command, rest @ ..] if command == "bench" => Ok(Command::Bench {
+            selection: parse_selection(rest)?,
+            options: bench,
+        }),
+        [command, rest @ ..] if command == "new" => match rest {
+            [year, day] | [year, day, _] => Ok(Command::New {
+                year: parse_num(year, "year")?,
+                day: parse_num(day, "day")?,
+                title: rest.get(2).map_or("TODO", |t| t.as_str()).to_string(),
+            }),
+            _ => Err("'new' requires a year and a day (and optionally a title)!".to_string()),
+        },
+        [command, rest @ ..] if command == "fetch" => match rest {
+            [year, day] => Ok(Command::Fetch {
+                year: parse_num(year, "year")?,
+                day: parse_num(day, "day")?,
+            }),
+            _ => Err("'fetch' requires a year and a day!".to_string()),
+        },
+        [command, rest @ ..] if command == "submit" => match parse_selection(rest)? {
+            Selection {
+                year,
+                days,
+                part: Some(part),
+            } if days.start() == days.end() => Ok(Command::Submit {
+                year,
+                day: *days.start(),
+                part,
+            }),
+            _ => Err("'submit' requires a year, a single day and a part!".to_string()),
+        },
+        [command, rest @ ..] if command == "watch" => match parse_selection(rest)? {
+            selection if selection.is_single_day() => Ok(Command::Watch {
+                selection,
+                example: None,
+            }),
+            _ => Err("'watch' requires a year and a single day!".to_string()),
+        },
+        _ => Ok(Command::Run(parse_selection(positional)?)),
+    };
+}
+
+/// Parse a selection of "YEAR [DAYS] [PART]".
+fn parse_selection(positional: &[String]) -> Result<Selection, String> {
+    let (year, days, part) = match positional {
+        [] => return Err("Missing year!".to_string()),
+        [year] => (year, None, None),
+        [year, days] => (year, Some(days), None),
+        [year, days, part] => (year, Some(days), Some(part)),
+        _ => return Err("Too many arguments!".to_string()),
+    };
+
+    let year = parse_num(year, "year")?;
+    let days = match days {
+        Some(days) => parse_days(days)?,
+        None => 1..=25,
+    };
+    let part = match part {
+        Some(part) => match Part::from_number(parse_num(part, "part")?) {
+            Some(part) => Some(part),
+            None => return Err(format!("Invalid part '{}', expected 1 or 2!", part)),
+        },
+        None => None,
+    };
+    return Ok(Selection { year, days, part });
+}
+
+/// Get the value following `flag`.
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    return args
+        .next()
+        .ok_or_else(|| format!("'{}' requires a value!", flag));
+}
+
+fn parse_num(s: &str, name: &str) -> Result<u32, String> {
+    return s.parse().map_err(|_| format!("Invalid {} '{}'!", name, s));
+}
+
+/// Parse a day selection such as "17", "all", "1..10" (exclusive) or "1..=10" (inclusive).
+fn parse_days(s: &str) -> Result<RangeInclusive<u32>, String> {
+    if s == "all" {
+        return Ok(1..=25);
+    }
+    let Some((start, end)) = s.split_once("..") else {
+        let day = parse_num(s, "day")?;
+        return Ok(day..=day);
+    };
+
+    let start = match start {
+        "" => 1,
+        start => parse_num(start, "day")?,
+    };
+    let end = match end.strip_prefix('=') {
+        Some(end) => parse_num(end, "day")?,
+        None if end.is_empty() => 25,
+        // * Exclusive ranges are converted to their inclusive equivalent.
+        None => parse_num(end, "day")?
+            .checked_sub(1)
+            .ok_or_else(|| format!("Invalid range of days '{}'!", s))?,
+    };
+    if start > end {
+        return Err(format!("Invalid range of days '{}'!", s));
+    }
+    return Ok(start..=end);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<Args, String> {
+        return parse(s.split_whitespace().map(String::from));
+    }
+
+    fn selection(s: &str) -> Result<Selection, String> {
+        return parse_selection(&s.split_whitespace().map(String::from).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_days("17"), Ok(17..=17));
+        assert_eq!(parse_days("all"), Ok(1..=25));
+        // * ".." is exclusive, like a Rust range.
+        assert_eq!(parse_days("1..10"), Ok(1..=9));
+        assert_eq!(parse_days("1..=10"), Ok(1..=10));
+        assert_eq!(parse_days("20.."), Ok(20..=25));
+        assert_eq!(parse_days("..=3"), Ok(1..=3));
+        assert!(parse_days("10..5").is_err());
+        assert!(parse_days("5..5").is_err());
+        assert!(parse_days("0..0").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn selections() {
+        assert_eq!(
+            selection("2024"),
+            Ok(Selection {
+                year: 2024,
+                days: 1..=25,
+                part: None
+            })
+        );
+        assert_eq!(
+            selection("2024 3..=5 2"),
+            Ok(Selection {
+                year: 2024,
+                days: 3..=5,
+                part: Some(Part::Two)
+            })
+        );
+        assert_eq!(selection("2024 7").unwrap().to_string(), "2024 day 7");
+        assert!(selection("").is_err());
+        assert!(selection("2024 1 3").is_err());
+        assert!(selection("2024 1 1 1").is_err());
+    }
+
+    #[test]
+    fn commands() {
+        let run = args("2024 1..3 1 --timeout 0.5 --jobs 4 --format json").unwrap();
+        assert_eq!(
+            run.command,
+            Command::Run(Selection {
+                year: 2024,
+                days: 1..=2,
+                part: Some(Part::One)
+            })
+        );
+        assert_eq!(run.timeout, Some(Duration::from_millis(500)));
+        assert_eq!((run.jobs, run.format), (4, Format::Json));
+
+        assert_eq!(args("").unwrap().command, Command::Help);
+        assert_eq!(args("2024 --help").unwrap().command, Command::Help);
+        assert_eq!(
+            args("--list 2023").unwrap().command,
+            Command::List(Some(2023))
+        );
+        assert_eq!(args("verify").unwrap().command, Command::Verify(None));
+        assert_eq!(
+            args("submit 2024 5 2").unwrap().command,
+            Command::Submit {
+                year: 2024,
+                day: 5,
+                part: Part::Two
+            }
+        );
+        assert_eq!(
+            args("bench 2024 5 --runs 3 --input -").unwrap(),
+            Args {
+                command: Command::Bench {
+                    selection: selection("2024 5").unwrap(),
+                    options: BenchOptions {
+                        runs: 3,
+                        ..BenchOptions::default()
+                    }
+                },
+                input: InputSource::Stdin,
+                timeout: None,
+                jobs: 1,
+                format: Format::Text,
+            }
+        );
+
+        assert!(args("2024 --runs 3").is_err());
+        assert!(args("2024 --input x").is_err());
+        assert!(args("2024 1 --jobs 0").is_err());
+        assert!(args("bench 2024 --jobs 2").is_err());
+        assert!(args("submit 2024 5").is_err());
+        assert!(args("2024 --bogus").is_err());
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    env, fs,
+    io::{Read, Write},
+    net::TcpStream,
+    path::Path,
+    time::Duration,
+};
+
+use advent_of_code::{answer::Answer, input, solution::Part};
+
+use crate::submit::{self, Verdict};
+
+/// The site that inputs are downloaded from, unless `BASE_URL_VAR` is set.
+/// Advent of Code only serves HTTPS, which `std` can't speak, so reaching it requires `PROXY_VAR` to be set
+/// to a proxy that forwards plain HTTP requests over TLS.
+pub const DEFAULT_BASE_URL: &str = "http://adventofcode.com";
+/// The environment variable that overrides `DEFAULT_BASE_URL`, e.g. to use a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// The environment variable containing the "host:port" of an HTTP proxy that requests are sent through.
+pub const PROXY_VAR: &str = "AOC_PROXY";
+/// The environment variable containing the session token (i.e. the value of the site's "session" cookie).
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// The file that the session token is read from if `SESSION_VAR` isn't set, which isn't committed.
+pub const SESSION_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.session");
+
+/// Identifies this tool (and where to find its author) to the site, as requested by Advent of Code.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/AstroTheRabbit/advent-of-code, std::net)"
+);
+
+/// How long to wait on the server before giving up.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A minimal HTTP/1.1 client for the Advent of Code site, authenticated with a session token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+    host: String,
+    port: u16,
+    /// The path of the base URL (without a trailing '/'), which every request's path is appended to.
+    prefix: String,
+    proxy: Option<String>,
+    session: String,
+}
+
+/// A server's response to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Client {
+    /// Create a client for `base_url`, which must be a plain "http://" URL.
+    pub fn new(base_url: &str, proxy: Option<String>, session: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid base URL '{}'!", base_url);
+        let Some(rest) = base_url.strip_prefix("http://") else {
+            return Err(format!(
+                "Invalid base URL '{}', only plain \"http://\" URLs are supported (set {} to use a TLS proxy)!",
+                base_url, PROXY_VAR
+            ));
+        };
+        let (authority, prefix) = match rest.find('/') {
+            Some(idx) => rest.split_at(idx),
+            None => (rest, ""),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        // * The token is sent as a header, so a line break in it could add headers (or end the request early).
+        let session = session.trim();
+        let session = session.strip_prefix("session=").unwrap_or(session);
+        if session.is_empty() {
+            return Err("The session token is empty!".to_string());
+        }
+        if session.contains(|c: char| c.is_control()) {
+            return Err(
+                "Invalid session token, it can't contain line breaks or other control characters!"
+                    .to_string(),
+            );
+        }
+        return Ok(Self {
+            host: host.to_string(),
+            port,
+            prefix: prefix.trim_end_matches('/').to_string(),
+            proxy,
+            session: session.to_string(),
+        });
+    }
+
+    /// Create a client from `BASE_URL_VAR`, `PROXY_VAR` and `SESSION_VAR` (or `SESSION_FILE`).
+    pub fn from_env() -> Result<Self, String> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let proxy = env::var(PROXY_VAR).ok().filter(|p| !p.is_empty());
+        // * Both sources are trimmed (and checked for line breaks) by `new`.
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) if !session.trim().is_empty() => session,
+            _ => fs::read_to_string(SESSION_FILE)
+                .ok()
+                .filter(|s| !s.trim().is_empty())
+                .ok_or_else(|| {
+                    format!(
+                        "Missing session token, set {} or save it to '{}'!",
+                        SESSION_VAR, SESSION_FILE
+                    )
+                })?,
+        };
+        return Self::new(&base_url, proxy, &session);
+    }
+
+    /// Download the puzzle input of a day.
+    pub fn input(&self, year: u32, day: u32) -> Result<String, String> {
+        let res = self.request("GET", &format!("/{}/day/{}/input", year, day), None)?;
+        return match res.status {
+            200 => Ok(res.body),
+            404 => Err(format!(
+                "The input of {} day {} isn't available yet (or doesn't exist)!",
+                year, day
+            )),
+            400 | 500 => Err(format!(
+                "The server rejected the request ({}), the session token may have expired!",
+                res.status
+            )),
+            status => Err(format!("Unexpected response from the server ({})!", status)),
+        };
+    }
+
+    /// Submit the answer to one part of a day, returning the site's verdict along with its message.
+    pub fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<(Verdict, String), String> {
+        let form = format!("level={}&answer={}", part, url_encode(&answer.to_string()));
+        let res = self.request(
+            "POST",
+            &format!("/{}/day/{}/answer", year, day),
+            Some(&form),
+        )?;
+        return match res.status {
+            200 => Ok(submit::parse_verdict(&res.body)),
+            400 | 500 => Err(format!(
+                "The server rejected the answer ({}), the session token may have expired!",
+                res.status
+            )),
+            status => Err(format!("Unexpected response from the server ({})!", status)),
+        };
+    }
+
+    /// Send a request to `path` (relative to the base URL), with `body` as a form if given.
+    pub fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<Response, String> {
+        let authority = match self.port {
+            80 => self.host.clone(),
+            port => format!("{}:{}", self.host, port),
+        };
+        let path = format!("{}{}", self.prefix, path);
+        // * Requests sent through a proxy contain the full URL, so that it knows where to forward them.
+        let (address, target) = match &self.proxy {
+            Some(proxy) => (proxy.clone(), format!("http://{}{}", authority, path)),
+            None => (authority.clone(), path),
+        };
+        let failed = |err: std::io::Error| format!("Request to '{}' failed: {}", address, err);
+
+        let mut req = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n",
+            method, target, authority, USER_AGENT, self.session
+        );
+        if let Some(body) = body {
+            req.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+            req.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        req.push_str("\r\n");
+        req.push_str(body.unwrap_or_default());
+
+        let mut stream = TcpStream::connect(&address).map_err(failed)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(failed)?;
+        stream.set_write_timeout(Some(TIMEOUT)).map_err(failed)?;
+        stream.write_all(req.as_bytes()).map_err(failed)?;
+        let mut res = Vec::new();
+        stream.read_to_end(&mut res).map_err(failed)?;
+        return parse_response(&res);
+    }
+}
+
+fn parse_response(res: &[u8]) -> Result<Response, String> {
+    let invalid = || "Invalid response from the server!".to_string();
+    let split = res
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(invalid)?;
+    let head = String::from_utf8_lossy(&res[..split]);
+    let mut body = &res[split + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(invalid)?;
+    let mut chunked = false;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (name, value) = (name.trim().to_ascii_lowercase(), value.trim());
+        if name == "transfer-encoding" && value.eq_ignore_ascii_case("chunked") {
+            chunked = true;
+        } else if name == "content-length" {
+            let len = value.parse().map_err(|_| invalid())?;
+            body = body.get(..len).ok_or_else(invalid)?;
+        }
+    }
+
+    let body = if chunked {
+        dechunk(body).ok_or_else(invalid)?
+    } else {
+        body.to_vec()
+    };
+    return Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).to_string(),
+    });
+}
+
+/// Decode a body sent with "Transfer-Encoding: chunked", i.e. chunks of "SIZE\r\nDATA\r\n" ending with a chunk of size 0.
+fn dechunk(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut res = Vec::new();
+    loop {
+        let end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&body[..end]).ok()?;
+        // * The size may be followed by extensions (e.g. "1a;name=value"), which are ignored.
+        let size = size.split(';').next()?.trim();
+        let size = usize::from_str_radix(size, 16).ok()?;
+        if size == 0 {
+            return Some(res);
+        }
+        res.extend_from_slice(body.get(end + 2..end + 2 + size)?);
+        body = body.get(end + 4 + size..)?;
+    }
+}
+
+/// Percent-encode `s` for use in a form, leaving only unreserved characters as they are.
+fn url_encode(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                res.push(b as char)
+            }
+            b => res.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    return res;
+}
+
+/// Save a downloaded input to `path`, which is written to a temporary file first so that a failed write can't be
+/// mistaken for a cached input.
+pub fn save_input(path: &Path, input: &str) -> Result<(), String> {
+    let failed = |err: std::io::Error| format!("Failed to write '{}': {}", path.display(), err);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(failed)?;
+    }
+    let tmp = path.with_extension("txt.part");
+    fs::write(&tmp, input).map_err(failed)?;
+    return fs::rename(&tmp, path).map_err(failed);
+}
+
+/// Download a day's input to its default path, unless that file already exists.
+pub fn fetch(year: u32, day: u32) -> Result<(), String> {
+    let path = input::default_path(year, day);
+    // * Inputs never change, so they're only downloaded once (which also avoids needlessly loading the site).
+    if path.exists() {
+        println!("'{}' already exists.", path.display());
+        return Ok(());
+    }
+    let input = Client::from_env()?.input(year, day)?;
+    save_input(&path, &input)?;
+    println!("Wrote '{}'.", path.display());
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Start a stand-in server that answers a single request with `response`, returning its address
+    /// and a handle that returns the request it received.
+    fn serve(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut req = String::new();
+            let mut len = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.strip_prefix("Content-Length: ") {
+                    len = value.trim().parse().unwrap();
+                }
+                req.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).unwrap();
+            req.push_str(&String::from_utf8(body).unwrap());
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            return req;
+        });
+        return (address, handle);
+    }
+
+    #[test]
+    fn fetch_input() {
+        let (address, server) = serve(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n3   \r\n7\r\n4\n4   3\r\n0\r\n\r\n",
+        );
+        let client =
+            Client::new(&format!("http://{}/aoc/", address), None, "session=abc\n").unwrap();
+        assert_eq!(client.input(2024, 1), Ok("3   4\n4   3".to_string()));
+
+        let req = server.join().unwrap();
+        assert!(req.starts_with("GET /aoc/2024/day/1/input HTTP/1.1\r\n"));
+        assert!(req.contains(&format!("\r\nHost: {}\r\n", address)));
+        assert!(req.contains("\r\nCookie: session=abc\r\n"));
+        assert!(req.contains("\r\nUser-Agent: advent-of-code/"));
+    }
+
+    #[test]
+    fn fetch_errors() {
+        let (address, server) = serve("HTTP/1.1 404 Not Found\r\nContent-Length: 5\r\n\r\nNope!");
+        let client = Client::new(&format!("http://{}", address), None, "abc").unwrap();
+        assert!(client
+            .input(2024, 25)
+            .unwrap_err()
+            .contains("isn't available yet"));
+        server.join().unwrap();
+
+        assert!(Client::new("https://adventofcode.com", None, "abc").is_err());
+        assert!(Client::new("http://:80", None, "abc").is_err());
+    }
+
+    #[test]
+    fn submit_answer() {
+        let (address, server) = serve(
+            "HTTP/1.1 200 OK\r\n\r\n<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+        let client = Client::new(&format!("http://{}", address), None, "abc").unwrap();
+        let (verdict, msg) = client
+            .submit(2024, 17, Part::Two, &Answer::Text("1,0 3".to_string()))
+            .unwrap();
+        assert_eq!(verdict, Verdict::TooLow);
+        assert_eq!(msg, "That's not the right answer; your answer is too low.");
+
+        let req = server.join().unwrap();
+        assert!(req.starts_with("POST /2024/day/17/answer HTTP/1.1\r\n"));
+        assert!(req.contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(req.ends_with("\r\n\r\nlevel=2&answer=1%2C0%203"));
+    }
+
+    #[test]
+    fn session_tokens() {
+        let client = |session| Client::new(DEFAULT_BASE_URL, None, session);
+        assert_eq!(client(" session=abc\r\n").unwrap().session, "abc");
+        assert_eq!(client("abc\n").unwrap().session, "abc");
+        assert!(client("abc\r\nX-Injected: 1").is_err());
+        assert!(client("abc\ndef").is_err());
+        assert!(client("session=\n").is_err());
+    }
+
+    #[test]
+    fn proxy() {
+        let (proxy, server) = serve("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok");
+        let client = Client::new(DEFAULT_BASE_URL, Some(proxy), "abc").unwrap();
+        assert_eq!(
+            client.request("POST", "/2024/day/1/answer", Some("level=1&answer=11")),
+            Ok(Response {
+                status: 200,
+                body: "ok".to_string()
+            })
+        );
+        let req = server.join().unwrap();
+        assert!(req.starts_with("POST http://adventofcode.com/2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(req.contains("\r\nHost: adventofcode.com\r\n"));
+        assert!(req.ends_with("\r\n\r\nlevel=1&answer=11"));
+    }
+}
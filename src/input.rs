@@ -0,0 +1,106 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::solution::Solution;
+
+/// The directory containing every day's puzzle input, as "yYYYY/dD.txt".
+pub const INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs");
+
+// * Defines `MANIFEST` and `EMBEDDED` from the contents of `INPUTS_DIR` (see "build.rs").
+include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+
+/// Whether `day` had an input file in `INPUTS_DIR` when the crate was built.
+pub fn in_manifest(year: u32, day: u32) -> bool {
+    return MANIFEST.contains(&(year, day));
+}
+
+/// Whether `day` has an input file, either when the crate was built or now (e.g. since it was fetched after building).
+/// Days without one are skipped when several days are run with their default input.
+pub fn available(year: u32, day: u32) -> bool {
+    return in_manifest(year, day) || default_path(year, day).exists();
+}
+
+/// The input of a day if it was embedded into the binary, which is only done if the `embed-inputs` feature is enabled.
+/// Days without an input file are left out rather than failing the build.
+pub const fn embedded(year: u32, day: u32) -> Option<&'static str> {
+    let mut i = 0;
+    while i < EMBEDDED.len() {
+        let (y, d, input) = EMBEDDED[i];
+        if y == year && d == day {
+            return Some(input);
+        }
+        i += 1;
+    }
+    return None;
+}
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's file in `INPUTS_DIR`, or its embedded copy if that file is missing (see the `embed-inputs` feature).
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(String, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(path) => write!(f, "Missing input file '{}'!", path.display()),
+            Self::Io(source, err) => write!(f, "Failed to read input from {}: {}", source, err),
+        }
+    }
+}
+
+pub fn default_path(year: u32, day: u32) -> PathBuf {
+    return Path::new(INPUTS_DIR)
+        .join(format!("y{}", year))
+        .join(format!("d{}.txt", day));
+}
+
+/// Read the puzzle input of `solution` from `source`.
+pub fn load(solution: &dyn Solution, source: &InputSource) -> Result<String, InputError> {
+    let input = match source {
+        InputSource::Default => {
+            let path = default_path(solution.year(), solution.day());
+            match (read_file(&path), solution.embedded_input()) {
+                (Err(InputError::Missing(_)), Some(embedded)) => embedded.to_string(),
+                (res, _) => res?,
+            }
+        }
+        InputSource::Path(path) => read_file(path)?,
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| InputError::Io("stdin".to_string(), err))?;
+            input
+        }
+    };
+    return Ok(normalize(&input));
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    return std::fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::Io(format!("'{}'", path.display()), err),
+    });
+}
+
+/// Convert line endings to '\n' and remove any trailing newlines.
+/// Some parsers (e.g. `y2024::d9`) treat every character of the input as data, so a trailing newline would break them.
+fn normalize(input: &str) -> String {
+    return input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string();
+}
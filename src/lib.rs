@@ -0,0 +1,37 @@
+//! Solutions to the puzzles of [Advent of Code](https://adventofcode.com), along with everything needed to run them.
+//! The "advent-of-code" binary is a command-line interface on top of this library,
+//! which also contains everything that only the command line needs (e.g. fetching inputs and submitting answers).
+//!
+//! ```
+//! use advent_of_code::{registry, Answer, Part};
+//!
+//! # #[cfg(feature = "y2024")] {
+//! // * Solvers can be found through the registry, which converts their answers into an `Answer`...
+//! let solution = registry::find(2024, 3).unwrap();
+//! let solver = solution.solver(Part::One).unwrap();
+//! assert_eq!(solver("xmul(2,4)&mul(3,7)"), Answer::Number(29));
+//!
+//! // * ...or called directly from their day's module.
+//! assert_eq!(advent_of_code::y2024::d3::solve_pt1("xmul(2,4)"), 8);
+//! # }
+//! ```
+
+#[cfg(feature = "y2023")]
+pub mod y2023;
+#[cfg(feature = "y2024")]
+pub mod y2024;
+#[cfg(feature = "y2025")]
+pub mod y2025;
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod input;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod solution;
+pub mod util;
+
+pub use answer::Answer;
+pub use solution::{Day, Part, Solution, Solver};
@@ -1,105 +1,50 @@
-pub mod y2023;
-pub mod y2024;
-pub mod y2025;
+mod cli;
+mod client;
+mod scaffold;
+mod submit;
+mod watch;
 
-#[macro_export]
-macro_rules! include_input {
-    ($year:expr, $day:expr) => {
-        include_str!(concat!("../../inputs/y", $year, "/d", $day, ".txt"))
-    };
-}
+use advent_of_code::{answers, bench, registry, runner};
+use cli::Command;
 
 fn main() {
-    println!("Hello, Advent of Code!");
-    // y2023();
-    // y2024();
-    y2025();
-}
-
-#[allow(dead_code)]
-fn y2023() {
-    println!("2023 Solutions");
-    println!("  day 1, part 1: {}", y2023::d1::solve_pt1());
-    println!("  day 1, part 2: {}", y2023::d1::solve_pt2());
-    println!("  day 2, part 1: {}", y2023::d2::solve_pt1());
-    println!("  day 2, part 2: {}", y2023::d2::solve_pt2());
-    println!("  day 3, part 1: {}", y2023::d3::solve_pt1());
-    println!("  day 3, part 2: {}", y2023::d3::solve_pt2());
-    println!("  day 4, part 1: {}", y2023::d4::solve_pt1());
-    println!("  day 4, part 2: {}", y2023::d4::solve_pt2()); // ! Slow
-    println!("  day 5, part 1: {}", y2023::d5::solve_pt1());
-    println!("  day 5, part 2: {}", y2023::d5::solve_pt2());
-    println!("  day 6, part 1: {}", y2023::d6::solve_pt1());
-    println!("  day 6, part 2: {}", y2023::d6::solve_pt2());
-}
-
-#[allow(dead_code)]
-fn y2024() {
-    println!("2024 Solutions");
-    println!("  day 1, part 1: {}", y2024::d1::solve_pt1());
-    println!("  day 1, part 2: {}", y2024::d1::solve_pt2());
-    println!("  day 2, part 1: {}", y2024::d2::solve_pt1());
-    println!("  day 2, part 2: {}", y2024::d2::solve_pt2());
-    println!("  day 3, part 1: {}", y2024::d3::solve_pt1());
-    println!("  day 3, part 2: {}", y2024::d3::solve_pt2());
-    println!("  day 4, part 1: {}", y2024::d4::solve_pt1());
-    println!("  day 4, part 2: {}", y2024::d4::solve_pt2());
-    println!("  day 5, part 1: {}", y2024::d5::solve_pt1());
-    println!("  day 5, part 2: {}", y2024::d5::solve_pt2());
-    println!("  day 6, part 1: {}", y2024::d6::solve_pt1());
-    println!("  day 6, part 2: {}", y2024::d6::solve_pt2()); // ! Slow
-    println!("  day 7, part 1: {}", y2024::d7::solve_pt1()); // ! Slow
-    println!("  day 7, part 2: {}", y2024::d7::solve_pt2()); // ! Slow
-    println!("  day 8, part 1: {}", y2024::d8::solve_pt1());
-    println!("  day 8, part 2: {}", y2024::d8::solve_pt2());
-    println!("  day 9, part 1: {}", y2024::d9::solve_pt1());
-    println!("  day 9, part 2: {}", y2024::d9::solve_pt2());
-    println!("  day 10, part 1: {}", y2024::d10::solve_pt1());
-    println!("  day 10, part 2: {}", y2024::d10::solve_pt2());
-    println!("  day 11, part 1: {}", y2024::d11::solve_pt1());
-    println!("  day 11, part 2: {}", y2024::d11::solve_pt2());
-    println!("  day 12, part 1: {}", y2024::d12::solve_pt1());
-    println!("  day 12, part 2: {}", y2024::d12::solve_pt2());
-    println!("  day 13, part 1: {}", y2024::d13::solve_pt1());
-    println!("  day 13, part 2: {}", y2024::d13::solve_pt2());
-    println!("  day 14, part 1: {}", y2024::d14::solve_pt1());
-    println!("  day 14, part 2: {}", y2024::d14::solve_pt2());
-    println!("  day 15, part 1: {}", y2024::d15::solve_pt1());
-    println!("  day 15, part 2: {}", y2024::d15::solve_pt2());
-    // println!("  day 16, part 1: {}", y2024::d16::solve_pt1()); // TODO
-    // println!("  day 16, part 2: {}", y2024::d16::solve_pt2()); // TODO
-    println!("  day 17, part 1: {}", y2024::d17::solve_pt1());
-    // println!("  day 17, part 2: {}", y2024::d17::solve_pt2()); // TODO
-    println!("  day 18, part 1: {}", y2024::d18::solve_pt1());
-    println!("  day 18, part 2: {}", y2024::d18::solve_pt2()); // ! Slow
-                                                               // println!("  day 19, part 1: {}", y2024::d19::solve_pt1()); // TODO
-                                                               // println!("  day 19, part 2: {}", y2024::d19::solve_pt2()); // TODO
-    println!("  day 20, part 1: {}", y2024::d20::solve_pt1()); // ! Slow
-    println!("  day 20, part 2: {}", y2024::d20::solve_pt2()); // ! Slow
-    println!("  day 22, part 1: {}", y2024::d22::solve_pt1());
-    println!("  day 22, part 2: {}", y2024::d22::solve_pt2()); // ! Slow
-    println!("  day 23, part 1: {}", y2024::d23::solve_pt1());
-    println!("  day 23, part 2: {}", y2024::d23::solve_pt2());
-    println!("  day 24, part 1: {}", y2024::d24::solve_pt1());
-    // println!("  day 24, part 2: {}", y2024::d24::solve_pt2()); // TODO
-}
+    let args = match cli::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
 
-fn y2025() {
-    println!("2025 Solutions");
-    // println!("  day 1, part 1: {}", y2025::d1::solve_pt1());
-    // println!("  day 1, part 2: {}", y2025::d1::solve_pt2());
-    // println!("  day 2, part 1: {}", y2025::d2::solve_pt1());
-    // println!("  day 2, part 2: {}", y2025::d2::solve_pt2());
-    // println!("  day 3, part 1: {}", y2025::d3::solve_pt1());
-    // println!("  day 3, part 2: {}", y2025::d3::solve_pt2()); // TODO
-    // println!("  day 4, part 1: {}", y2025::d4::solve_pt1());
-    // println!("  day 4, part 2: {}", y2025::d4::solve_pt2());
-    // println!("  day 5, part 1: {}", y2025::d5::solve_pt1());
-    // println!("  day 5, part 2: {}", y2025::d5::solve_pt2());
-    // println!("  day 6, part 1: {}", y2025::d6::solve_pt1());
-    // println!("  day 6, part 2: {}", y2025::d6::solve_pt2());
-    // println!("  day 7, part 1: {}", y2025::d7::solve_pt1());
-    // println!("  day 7, part 2: {}", y2025::d7::solve_pt2());
-    // println!("  day 8, part 1: {}", y2025::d8::solve_pt1());
-    println!("  day 8, part 2: {}", y2025::d8::solve_pt2());
+    let res = match &args.command {
+        Command::Run(selection) => {
+            runner::run(selection, &args.input, args.jobs, args.timeout, args.format)
+        }
+        Command::List(year) => {
+            registry::list(*year);
+            Ok(())
+        }
+        Command::Verify(selection) => {
+            answers::verify(selection.as_ref(), args.jobs, args.timeout, args.format)
+        }
+        Command::Bench { selection, options } => {
+            bench::run(selection, &args.input, *options, args.timeout)
+        }
+        Command::New { year, day, title } => scaffold::new(*year, *day, title),
+        Command::Fetch { year, day } => client::fetch(*year, *day),
+        Command::Submit { year, day, part } => {
+            submit::run(*year, *day, *part, &args.input, args.timeout)
+        }
+        Command::Watch { selection, example } => {
+            watch::run(selection, example.clone(), args.timeout)
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    };
+    if let Err(err) = res {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
 }
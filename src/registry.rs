@@ -0,0 +1,66 @@
+use crate::{
+    input,
+    solution::{Day, Part, Solution},
+};
+
+/// The registered days of every year whose feature is enabled, sorted by year (see `scaffold::new_day`).
+#[rustfmt::skip]
+const YEARS: &[&[Day]] = &[
+    #[cfg(feature = "y2023")] crate::y2023::DAYS,
+    #[cfg(feature = "y2024")] crate::y2024::DAYS,
+    #[cfg(feature = "y2025")] crate::y2025::DAYS,
+];
+
+/// Iterate through every registered solution, sorted by year then day.
+pub fn all() -> impl Iterator<Item = &'static dyn Solution> {
+    return YEARS
+        .iter()
+        .flat_map(|days| days.iter())
+        .map(|day| day as &dyn Solution);
+}
+
+/// Iterate through the registered solutions for `year`, sorted by day.
+pub fn year(year: u32) -> impl Iterator<Item = &'static dyn Solution> {
+    return all().filter(move |s| s.year() == year);
+}
+
+/// Get the registered solution for a single day.
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Solution> {
+    return all().find(|s| s.year() == year && s.day() == day);
+}
+
+/// Every year with at least one registered solution.
+pub fn years() -> Vec<u32> {
+    let mut years = all().map(|s| s.year()).collect::<Vec<_>>();
+    years.dedup();
+    return years;
+}
+
+/// Print every registered solution (or only those of `year`), along with which parts are implemented and whether its input is missing.
+pub fn list(year: Option<u32>) {
+    for y in years() {
+        if year.is_some_and(|year| year != y) {
+            continue;
+        }
+        println!("{} Solutions", y);
+        for solution in self::year(y) {
+            let parts = match solution.parts().as_slice() {
+                [Part::One, Part::Two] => "parts 1 & 2".to_string(),
+                [part] => format!("part {}", part),
+                _ => continue,
+            };
+            let no_input = if input::in_manifest(y, solution.day()) {
+                ""
+            } else {
+                " [no input]"
+            };
+            println!(
+                "  day {}: {} ({}){}",
+                solution.day(),
+                solution.title(),
+                parts,
+                no_input
+            );
+        }
+    }
+}
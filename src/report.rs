@@ -0,0 +1,282 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::{
+    answer::Answer,
+    bench,
+    runner::{Outcome, PartResult},
+    solution::Part,
+};
+
+/// How the results of running (or verifying) solutions are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Human-readable lines, printed as each part finishes.
+    #[default]
+    Text,
+    Json,
+    Csv,
+    /// A table suitable for pasting into a README.
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "Unknown format '{}', expected text, json, csv or markdown!",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Todo,
+    Panicked,
+    TimedOut,
+    NoInput,
+    /// The answer matches the known answer (when verifying).
+    Pass,
+    /// The answer doesn't match the known answer, or the part failed to produce one (when verifying).
+    Fail,
+    /// The part has no input, answer or known answer to compare (when verifying).
+    Missing,
+}
+
+impl Status {
+    pub fn of(outcome: &Outcome) -> Self {
+        match outcome {
+            Outcome::Solved(_) => Self::Solved,
+            Outcome::Todo => Self::Todo,
+            Outcome::Panicked(_) => Self::Panicked,
+            Outcome::TimedOut(_) => Self::TimedOut,
+            Outcome::InputError(_) => Self::NoInput,
+        }
+    }
+
+    /// The status of a part when verifying it against its `expected` answer (if it's known).
+    pub fn verify(outcome: &Outcome, expected: Option<&Answer>) -> Self {
+        match (outcome, expected) {
+            (Outcome::Solved(answer), Some(expected)) if answer == expected => Self::Pass,
+            (Outcome::Solved(_), None) | (Outcome::Todo | Outcome::InputError(_), _) => {
+                Self::Missing
+            }
+            _ => Self::Fail,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Todo => "todo",
+            Self::Panicked => "panicked",
+            Self::TimedOut => "timed out",
+            Self::NoInput => "no input",
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+/// The result of one part, as it appears in the machine-readable formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+impl Record {
+    pub fn new(res: &PartResult, status: Status) -> Self {
+        let answer = match &res.outcome {
+            Outcome::Solved(answer) => Some(answer.clone()),
+            _ => None,
+        };
+        return Self {
+            year: res.year,
+            day: res.day,
+            part: res.part,
+            answer,
+            duration: res.duration,
+            status,
+        };
+    }
+}
+
+/// Format `records` as `format`, which shouldn't be `Format::Text` (since that is printed as the parts finish instead).
+pub fn render(format: Format, records: &[Record]) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Text => (),
+        Format::Json => {
+            out.push('[');
+            for (i, r) in records.iter().enumerate() {
+                let answer = match &r.answer {
+                    Some(answer) => json_string(&answer.to_string()),
+                    None => "null".to_string(),
+                };
+                let _ = write!(
+                    out,
+                    "{}\n  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ms\": {:.3}, \"status\": {}}}",
+                    if i == 0 { "" } else { "," },
+                    r.year,
+                    r.day,
+                    r.part,
+                    answer,
+                    millis(r.duration),
+                    json_string(r.status.name())
+                );
+            }
+            out.push_str(if records.is_empty() { "]" } else { "\n]" });
+        }
+        Format::Csv => {
+            out.push_str("year,day,part,answer,duration_ms,status");
+            for r in records {
+                let answer = r.answer.as_ref().map(|a| a.to_string()).unwrap_or_default();
+                let _ = write!(
+                    out,
+                    "\n{},{},{},{},{:.3},{}",
+                    r.year,
+                    r.day,
+                    r.part,
+                    csv_field(&answer),
+                    millis(r.duration),
+                    r.status.name()
+                );
+            }
+        }
+        Format::Markdown => {
+            out.push_str("| Year | Day | Part | Answer | Time | Status |\n");
+            out.push_str("| ---: | --: | ---: | :----- | ---: | :----- |");
+            for r in records {
+                let answer = r.answer.as_ref().map(|a| a.to_string()).unwrap_or_default();
+                let _ = write!(
+                    out,
+                    "\n| {} | {} | {} | {} | {} | {} |",
+                    r.year,
+                    r.day,
+                    r.part,
+                    answer.replace('|', "\\|"),
+                    bench::fmt_duration(r.duration),
+                    r.status.name()
+                );
+            }
+        }
+    }
+    return out;
+}
+
+fn millis(duration: Duration) -> f64 {
+    return duration.as_secs_f64() * 1000.0;
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(res, "\\u{:04x}", c as u32);
+            }
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    return res;
+}
+
+/// Quote a CSV field if it contains a comma, quote or newline (e.g. answers such as "1,0,3").
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", s.replace('"', "\"\""));
+    }
+    return s.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        return vec![
+            Record {
+                year: 2024,
+                day: 17,
+                part: Part::One,
+                answer: Some(Answer::Text("4,6,3".to_string())),
+                duration: Duration::from_micros(1500),
+                status: Status::Solved,
+            },
+            Record {
+                year: 2024,
+                day: 24,
+                part: Part::Two,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Todo,
+            },
+        ];
+    }
+
+    #[test]
+    fn verifying() {
+        let (right, wrong) = (Answer::Number(7), Answer::Number(8));
+        let solved = Outcome::Solved(right.clone());
+        assert_eq!(Status::verify(&solved, Some(&right)), Status::Pass);
+        assert_eq!(Status::verify(&solved, Some(&wrong)), Status::Fail);
+        assert_eq!(Status::verify(&solved, None), Status::Missing);
+        assert_eq!(
+            Status::verify(&Outcome::Todo, Some(&right)),
+            Status::Missing
+        );
+        let no_input = Outcome::InputError("Missing".to_string());
+        assert_eq!(Status::verify(&no_input, Some(&right)), Status::Missing);
+        let timed_out = Outcome::TimedOut(Duration::from_secs(1));
+        assert_eq!(Status::verify(&timed_out, Some(&right)), Status::Fail);
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json, &records()),
+            "[\n  {\"year\": 2024, \"day\": 17, \"part\": 1, \"answer\": \"4,6,3\", \"duration_ms\": 1.500, \"status\": \"solved\"},\
+             \n  {\"year\": 2024, \"day\": 24, \"part\": 2, \"answer\": null, \"duration_ms\": 0.000, \"status\": \"todo\"}\n]"
+        );
+        assert_eq!(render(Format::Json, &[]), "[]");
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv, &records()),
+            "year,day,part,answer,duration_ms,status\n2024,17,1,\"4,6,3\",1.500,solved\n2024,24,2,,0.000,todo"
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let table = render(Format::Markdown, &records());
+        assert_eq!(
+            table.lines().nth(2),
+            Some("| 2024 | 17 | 1 | 4,6,3 | 1.50ms | solved |")
+        );
+    }
+}
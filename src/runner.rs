@@ -0,0 +1,470 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt::Display,
+    ops::RangeInclusive,
+    panic::{self, PanicHookInfo},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    bench,
+    input::{self, InputSource},
+    registry,
+    report::{self, Format, Record, Status},
+    solution::{Part, Solution, Solver},
+};
+
+/// The result of running a part's solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    /// The solver called `unimplemented!()` or `todo!()`.
+    Todo,
+    /// The solver panicked with a message (and the location of the panic, if known).
+    Panicked(String),
+    /// The solver didn't finish within the given time.
+    TimedOut(Duration),
+    /// The solver wasn't run since its day's input couldn't be loaded.
+    InputError(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "{}", answer),
+            Self::Todo => write!(f, "TODO"),
+            Self::Panicked(msg) => write!(f, "panicked: {}", msg),
+            Self::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+            Self::InputError(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+thread_local! {
+    /// Whether this thread is currently running a solver within `execute`.
+    static EXECUTING: Cell<bool> = const { Cell::new(false) };
+    /// The location of the last panic of a solver on this thread, recorded by the panic hook installed by `execute`.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Wrap the current panic hook (which prints every panic to stderr) so that the panics of solvers are only recorded,
+/// since they're instead reported by `execute`. Any other panic is still passed to the original hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info: &PanicHookInfo| {
+            if EXECUTING.get() {
+                let location = info.location().map(|l| l.to_string());
+                PANIC_LOCATION.set(location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Run `solver` on `input`, catching any panic so that it doesn't abort the rest of the run.
+pub fn execute(solver: Solver, input: &str) -> Outcome {
+    install_panic_hook();
+    EXECUTING.set(true);
+    let res = panic::catch_unwind(|| solver(input));
+    EXECUTING.set(false);
+    let payload = match res {
+        Ok(answer) => return Outcome::Solved(answer),
+        Err(payload) => payload,
+    };
+
+    let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "<unknown>".to_string()
+    };
+    // * The messages of `unimplemented!()` and `todo!()`, which may be followed by a custom message.
+    if msg.starts_with("not implemented") || msg.starts_with("not yet implemented") {
+        return Outcome::Todo;
+    }
+    return match PANIC_LOCATION.take() {
+        Some(location) => Outcome::Panicked(format!("{} (at {})", msg, location)),
+        None => Outcome::Panicked(msg),
+    };
+}
+
+/// The stack size of the threads that solvers are run on, which matches that of the main thread (on most platforms).
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Run `solver` on `input` like `execute`, but give up on it if it takes longer than `timeout`.
+/// The solver is run on its own thread, which is left running in the background if it times out
+/// (since a thread can't be stopped from the outside), until it finishes or the program exits.
+pub fn execute_with_timeout(
+    solver: Solver,
+    input: &Arc<str>,
+    timeout: Option<Duration>,
+) -> Outcome {
+    let Some(timeout) = timeout else {
+        return execute(solver, input);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let input = Arc::clone(input);
+    let spawned = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // * The receiver will have been dropped if the solver timed out, in which case the outcome is irrelevant.
+            let _ = sender.send(execute(solver, &input));
+        });
+    if let Err(err) = spawned {
+        return Outcome::Panicked(format!("Failed to spawn solver thread: {}", err));
+    }
+    return match receiver.recv_timeout(timeout) {
+        Ok(outcome) => outcome,
+        Err(mpsc::RecvTimeoutError::Timeout) => Outcome::TimedOut(timeout),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Outcome::Panicked("Solver thread exited without an answer".to_string())
+        }
+    };
+}
+
+/// The days and parts of a single year that should be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub year: u32,
+    pub days: RangeInclusive<u32>,
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    pub fn is_single_day(&self) -> bool {
+        return self.days.start() == self.days.end();
+    }
+
+    pub fn contains(&self, year: u32, day: u32, part: Part) -> bool {
+        return self.year == year
+            && self.days.contains(&day)
+            && self.part.is_none_or(|p| p == part);
+    }
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.year)?;
+        let (start, end) = (self.days.start(), self.days.end());
+        if start == end {
+            write!(f, " day {}", start)?;
+        } else {
+            write!(f, " days {}..={}", start, end)?;
+        }
+        if let Some(part) = self.part {
+            write!(f, " part {}", part)?;
+        }
+        return Ok(());
+    }
+}
+
+/// The implemented parts of `solution` that are included in `selection` (or all of them if there's no selection).
+pub fn selected_parts(solution: &dyn Solution, selection: Option<&Selection>) -> Vec<Part> {
+    return solution
+        .parts()
+        .into_iter()
+        .filter(|&p| selection.is_none_or(|s| s.contains(solution.year(), solution.day(), p)))
+        .collect();
+}
+
+/// One part of a day that should be run.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub solver: Solver,
+    /// The day's input, or why it couldn't be loaded.
+    pub input: Result<Arc<str>, String>,
+}
+
+impl Job {
+    fn run(&self, timeout: Option<Duration>) -> PartResult {
+        let (outcome, duration) = match &self.input {
+            Ok(input) => {
+                let start = Instant::now();
+                let outcome = execute_with_timeout(self.solver, input, timeout);
+                (outcome, start.elapsed())
+            }
+            Err(err) => (Outcome::InputError(err.clone()), Duration::ZERO),
+        };
+        return PartResult {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            outcome,
+            duration,
+        };
+    }
+}
+
+/// The outcome of a `Job`, along with how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub duration: Duration,
+}
+
+/// Whether the input of `solution` can be loaded from `source`, which is only false for the default input of a day without an input file.
+pub fn has_input(solution: &dyn Solution, source: &InputSource) -> bool {
+    return *source != InputSource::Default || input::available(solution.year(), solution.day());
+}
+
+/// Create a job for each of the selected parts of `solutions` (or all of their parts if there's no selection),
+/// loading each day's input from `source`. Days without an input file are left out if `skip_missing` is set,
+/// and otherwise get jobs that report the missing file.
+pub fn jobs(
+    solutions: impl Iterator<Item = &'static dyn Solution>,
+    selection: Option<&Selection>,
+    source: &InputSource,
+    skip_missing: bool,
+) -> Vec<Job> {
+    let mut jobs = Vec::new();
+    for solution in solutions {
+        let parts = selected_parts(solution, selection);
+        if parts.is_empty() || (skip_missing && !has_input(solution, source)) {
+            continue;
+        }
+        let input = input::load(solution, source)
+            .map(Arc::from)
+            .map_err(|err| err.to_string());
+        for part in parts {
+            if let Some(solver) = solution.solver(part) {
+                jobs.push(Job {
+                    year: solution.year(),
+                    day: solution.day(),
+                    part,
+                    solver,
+                    input: input.clone(),
+                });
+            }
+        }
+    }
+    return jobs;
+}
+
+/// Run `jobs` across `threads` worker threads, passing each result to `report` in the same order as `jobs`
+/// (i.e. as soon as it and every result before it have finished).
+pub fn run_jobs(
+    jobs: &[Job],
+    threads: usize,
+    timeout: Option<Duration>,
+    mut report: impl FnMut(PartResult),
+) {
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            let sender = sender.clone();
+            let next_job = &next_job;
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    // * Each worker takes the next job that hasn't been started yet until there are none left.
+                    loop {
+                        let idx = next_job.fetch_add(1, Ordering::Relaxed);
+                        let Some(job) = jobs.get(idx) else {
+                            break;
+                        };
+                        let _ = sender.send((idx, job.run(timeout)));
+                    }
+                })
+                .expect("Failed to spawn worker thread!");
+        }
+        drop(sender);
+
+        // * Results that finished before an earlier job are held back until it has been reported.
+        let mut pending = BTreeMap::new();
+        let mut next_report = 0;
+        for (idx, result) in receiver {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&next_report) {
+                report(result);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+/// Run the selected solutions on their inputs (from `source`), printing their results as `format`.
+pub fn run(
+    selection: &Selection,
+    source: &InputSource,
+    threads: usize,
+    timeout: Option<Duration>,
+    format: Format,
+) -> Result<(), String> {
+    // * Days without an input are only skipped when several days are selected, so that selecting one names its missing file.
+    let skip_missing = !selection.is_single_day();
+    let jobs = jobs(
+        registry::year(selection.year),
+        Some(selection),
+        source,
+        skip_missing,
+    );
+    if jobs.is_empty() {
+        return Err(format!(
+            "No solutions with an input found for {}!",
+            selection
+        ));
+    }
+
+    let text = format == Format::Text;
+    if text {
+        println!("{} Solutions", selection.year);
+    }
+    let mut records = Vec::new();
+    // * A day's input error is only reported once, rather than for each of its parts.
+    let mut input_error_day = None;
+    run_jobs(&jobs, threads, timeout, |res| {
+        if text {
+            match &res.outcome {
+                Outcome::InputError(err) => {
+                    if input_error_day.replace(res.day) != Some(res.day) {
+                        println!("  day {}: {}", res.day, err);
+                    }
+                }
+                Outcome::Solved(answer) => println!(
+                    "  day {}, part {}: {} ({})",
+                    res.day,
+                    res.part,
+                    answer,
+                    bench::fmt_duration(res.duration)
+                ),
+                outcome => println!("  day {}, part {}: {}", res.day, res.part, outcome),
+            }
+        }
+        records.push(Record::new(&res, Status::of(&res.outcome)));
+    });
+    if !text {
+        println!("{}", report::render(format, &records));
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Day;
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(
+            execute(|input| input.len().into(), "abc"),
+            Outcome::Solved(Answer::Number(3))
+        );
+        assert_eq!(execute(|_| unimplemented!(), ""), Outcome::Todo);
+        assert_eq!(execute(|_| todo!("pt. 2"), ""), Outcome::Todo);
+
+        let Outcome::Panicked(msg) = execute(|input| input.parse::<u32>().unwrap().into(), "x")
+        else {
+            panic!("Expected a panic!");
+        };
+        assert!(msg.starts_with("called `Result::unwrap()` on an `Err` value"));
+        assert!(msg.contains("src/runner.rs"));
+    }
+
+    #[test]
+    fn timeouts() {
+        let input: Arc<str> = "abc".into();
+        let timeout = Some(Duration::from_millis(50));
+        assert_eq!(
+            execute_with_timeout(|input| input.len().into(), &input, timeout),
+            Outcome::Solved(Answer::Number(3))
+        );
+        assert_eq!(
+            execute_with_timeout(|_| unimplemented!(), &input, timeout),
+            Outcome::Todo
+        );
+        assert_eq!(
+            execute_with_timeout(
+                |_| {
+                    thread::sleep(Duration::from_secs(1));
+                    0.into()
+                },
+                &input,
+                timeout
+            ),
+            Outcome::TimedOut(Duration::from_millis(50))
+        );
+    }
+
+    #[test]
+    fn days_without_input() {
+        // * There's never an input file for day 26.
+        static DAYS: [Day; 2] = [
+            crate::day!(2024, 26, "One Part", str::len),
+            crate::day!(2024, 26, "Two Parts", str::len, str::len),
+        ];
+        let days = || DAYS.iter().map(|day| day as &dyn Solution);
+        assert!(jobs(days(), None, &InputSource::Default, true).is_empty());
+
+        // * Without skipping, the jobs report the missing file instead.
+        let missing = jobs(days(), None, &InputSource::Default, false);
+        assert_eq!(missing.len(), 3);
+        let path = input::default_path(2024, 26);
+        for job in missing {
+            assert_eq!(
+                job.input.unwrap_err(),
+                format!("Missing input file '{}'!", path.display())
+            );
+        }
+
+        // * An input read from elsewhere is never skipped.
+        let given = jobs(days(), None, &InputSource::Path("-".into()), true);
+        assert_eq!(given.len(), 3);
+    }
+
+    #[test]
+    fn jobs_are_reported_in_order() {
+        let job = |day, solver: Solver| Job {
+            year: 2024,
+            day,
+            part: Part::One,
+            solver,
+            input: Ok("".into()),
+        };
+        let mut jobs = vec![
+            job(1, |_| {
+                thread::sleep(Duration::from_millis(50));
+                1.into()
+            }),
+            job(2, |_| 2.into()),
+            job(3, |_| panic!("Oops!")),
+        ];
+        jobs.push(Job {
+            input: Err("Missing input!".to_string()),
+            ..job(4, |_| 4.into())
+        });
+
+        for threads in [1, 4] {
+            let mut results = Vec::new();
+            run_jobs(&jobs, threads, None, |res| {
+                results.push((res.day, res.outcome))
+            });
+            assert_eq!(results[0], (1, Outcome::Solved(Answer::Number(1))));
+            assert_eq!(results[1], (2, Outcome::Solved(Answer::Number(2))));
+            assert!(matches!(results[2], (3, Outcome::Panicked(_))));
+            assert_eq!(
+                results[3],
+                (4, Outcome::InputError("Missing input!".to_string()))
+            );
+        }
+    }
+}
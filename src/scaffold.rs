@@ -0,0 +1,321 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The crate's source directory, which new days are added to.
+pub const SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// Add a new day to the source directory `src`, which:
+/// - Creates "yYYYY/dD.rs" from a template.
+/// - Adds `pub mod dD;` to the year's "mod.rs" and registers the day in its `DAYS`.
+/// - Creates the year's module if it doesn't exist yet, registering it in "lib.rs" and "registry.rs"
+///   behind a new (default) `yYYYY` feature in "Cargo.toml", which is expected to be in the parent of `src`.
+///
+/// Returns every file that was created or modified.
+pub fn new_day(src: &Path, year: u32, day: u32, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{}', expected 1 to 25!", day));
+    }
+    let year_dir = src.join(format!("y{}", year));
+    let day_path = year_dir.join(format!("d{}.rs", day));
+    let mod_path = year_dir.join("mod.rs");
+    if day_path.exists() {
+        return Err(format!("'{}' already exists!", day_path.display()));
+    }
+
+    // * Every file is prepared before any are written, so that nothing is left half-done if one of them can't be updated.
+    let mut writes = Vec::new();
+    if mod_path.exists() {
+        let mod_rs = read(&mod_path)?;
+        let mod_rs = insert_day(&insert_mod(&mod_rs, day)?, year, day, title)?;
+        writes.push((mod_path, mod_rs));
+    } else {
+        writes.push((mod_path, year_template(year, day, title)));
+        let lib_path = src.join("lib.rs");
+        writes.push((lib_path.clone(), insert_year_mod(&read(&lib_path)?, year)?));
+        let registry_path = src.join("registry.rs");
+        writes.push((
+            registry_path.clone(),
+            insert_year(&read(&registry_path)?, year)?,
+        ));
+        let manifest_path = src.parent().unwrap_or(src).join("Cargo.toml");
+        writes.push((
+            manifest_path.clone(),
+            insert_feature(&read(&manifest_path)?, year)?,
+        ));
+    }
+    writes.push((day_path, DAY_TEMPLATE.to_string()));
+
+    fs::create_dir_all(&year_dir)
+        .map_err(|err| format!("Failed to create '{}': {}", year_dir.display(), err))?;
+    let mut written = Vec::new();
+    for (path, contents) in writes {
+        fs::write(&path, contents)
+            .map_err(|err| format!("Failed to write '{}': {}", path.display(), err))?;
+        written.push(path);
+    }
+    return Ok(written);
+}
+
+/// Add a new day to `SRC_DIR` (see `new_day`), printing every file that was written.
+pub fn new(year: u32, day: u32, title: &str) -> Result<(), String> {
+    for path in new_day(Path::new(SRC_DIR), year, day, title)? {
+        println!("Wrote '{}'.", path.display());
+    }
+    println!(
+        "Added {} day {}, which can be run after rebuilding.",
+        year, day
+    );
+    return Ok(());
+}
+
+const DAY_TEMPLATE: &str = "\
+pub fn solve_pt1(_input: &str) -> u32 {
+    unimplemented!();
+}
+
+pub fn solve_pt2(_input: &str) -> u32 {
+    unimplemented!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO: Add the puzzle's example input and answers, then remove `#[ignore]`.
+    const EXAMPLE: &str = \"\\
+\";
+
+    #[test]
+    #[ignore]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 0);
+    }
+
+    #[test]
+    #[ignore]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 0);
+    }
+}
+";
+
+fn year_template(year: u32, day: u32, title: &str) -> String {
+    return format!(
+        "use crate::{{day, solution::Day}};\n\npub mod d{day};\n\n#[rustfmt::skip]\npub const DAYS: &[Day] = &[\n{}];\n",
+        day_entry(year, day, title)
+    );
+}
+
+fn day_entry(year: u32, day: u32, title: &str) -> String {
+    return format!(
+        "    day!({}, {}, {:?}, d{}::solve_pt1, d{}::solve_pt2),\n",
+        year, day, title, day, day
+    );
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    return fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read '{}': {}", path.display(), err));
+}
+
+/// Insert `line` into `text` among the lines for which `key` returns `Some`, keeping them sorted by their keys.
+/// If `line` spans several lines, then so does every existing entry, each ending with the line that `key` is found on.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    line: &str,
+    new_key: K,
+    key: impl Fn(&str) -> Option<K>,
+) -> Result<String, String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect::<Vec<_>>();
+    let Some((last, _)) = keyed.last() else {
+        return Err(format!("Couldn't find where to insert '{}'!", line.trim()));
+    };
+    if keyed.iter().any(|(_, k)| *k == new_key) {
+        return Err(format!("'{}' is already registered!", line.trim()));
+    }
+    let height = line.trim_end_matches('\n').lines().count();
+    let idx = keyed
+        .iter()
+        .find(|(_, k)| *k > new_key)
+        .map_or(last + 1, |(i, _)| *i + 1 - height);
+
+    lines.insert(idx, line.trim_end_matches('\n'));
+    let mut res = lines.join("\n");
+    res.push('\n');
+    return Ok(res);
+}
+
+/// Add `pub mod dD;` to a year's "mod.rs", which are sorted lexically (i.e. "d1, d10, d11, ..., d2, d20, ...").
+fn insert_mod(mod_rs: &str, day: u32) -> Result<String, String> {
+    return insert_sorted(
+        mod_rs,
+        &format!("pub mod d{};", day),
+        format!("d{}", day),
+        |l| {
+            let name = l.strip_prefix("pub mod ")?.strip_suffix(';')?;
+            return name.starts_with('d').then(|| name.to_string());
+        },
+    );
+}
+
+/// Register a day in a year's `DAYS`, which are sorted by day.
+fn insert_day(mod_rs: &str, year: u32, day: u32, title: &str) -> Result<String, String> {
+    let prefix = format!("day!({}, ", year);
+    return insert_sorted(mod_rs, &day_entry(year, day, title), day, |l| {
+        let (d, _) = l.trim().strip_prefix(&prefix)?.split_once(',')?;
+        return d.parse().ok();
+    });
+}
+
+/// Add `pub mod yYYYY;` to "lib.rs", gated behind the year's feature.
+fn insert_year_mod(lib_rs: &str, year: u32) -> Result<String, String> {
+    let line = format!("#[cfg(feature = \"y{year}\")]\npub mod y{year};");
+    return insert_sorted(lib_rs, &line, year, |l| {
+        return l.strip_prefix("pub mod y")?.strip_suffix(';')?.parse().ok();
+    });
+}
+
+/// Register a year's `DAYS` in the registry's `YEARS`, gated behind the year's feature.
+fn insert_year(registry_rs: &str, year: u32) -> Result<String, String> {
+    let line = format!("    #[cfg(feature = \"y{year}\")] crate::y{year}::DAYS,");
+    return insert_sorted(registry_rs, &line, year, |l| {
+        let (_, path) = l.trim().split_once("crate::y")?;
+        return path.strip_suffix("::DAYS,")?.parse().ok();
+    });
+}
+
+/// Add a year's feature to "Cargo.toml", enabling it by default.
+fn insert_feature(cargo_toml: &str, year: u32) -> Result<String, String> {
+    let feature = format!("y{}", year);
+    let res = insert_sorted(cargo_toml, &format!("{} = []", feature), year, |l| {
+        return l.strip_prefix('y')?.strip_suffix(" = []")?.parse().ok();
+    })?;
+
+    let mut lines = res.lines().map(str::to_string).collect::<Vec<_>>();
+    let Some(default) = lines.iter_mut().find(|l| l.starts_with("default = [")) else {
+        return Err("Couldn't find the default features!".to_string());
+    };
+    let list = default
+        .strip_prefix("default = [")
+        .and_then(|l| l.strip_suffix(']'))
+        .ok_or_else(|| format!("Couldn't parse '{}'!", default))?;
+    let mut features = list
+        .split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    features.push(format!("{:?}", feature));
+    *default = format!("default = [{}]", features.join(", "));
+
+    let mut res = lines.join("\n");
+    res.push('\n');
+    return Ok(res);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "\
+use crate::{day, solution::Day};
+
+pub mod d1;
+pub mod d10;
+pub mod d2;
+
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, \"One\", d1::solve_pt1, d1::solve_pt2),
+    day!(2024, 2, \"Two\", d2::solve_pt1, d2::solve_pt2),
+    day!(2024, 10, \"Ten\", d10::solve_pt1),
+];
+";
+
+    #[test]
+    fn mods_are_sorted_lexically() {
+        let res = insert_mod(MOD_RS, 11).unwrap();
+        assert!(res.contains("pub mod d10;\npub mod d11;\npub mod d2;\n"));
+        let res = insert_mod(MOD_RS, 3).unwrap();
+        assert!(res.contains("pub mod d2;\npub mod d3;\n\n"));
+        assert!(insert_mod(MOD_RS, 2).is_err());
+    }
+
+    #[test]
+    fn days_are_sorted_numerically() {
+        let res = insert_day(MOD_RS, 2024, 3, "Three \"3\"").unwrap();
+        assert!(res.contains(
+            "d2::solve_pt2),\n    day!(2024, 3, \"Three \\\"3\\\"\", d3::solve_pt1, d3::solve_pt2),\n    day!(2024, 10,"
+        ));
+        let res = insert_day(MOD_RS, 2024, 25, "Last").unwrap();
+        assert!(res.ends_with("d25::solve_pt2),\n];\n"));
+        assert!(insert_day(MOD_RS, 2024, 10, "Ten").is_err());
+    }
+
+    #[test]
+    fn gated_years_are_sorted() {
+        let lib_rs = "#[cfg(feature = \"y2023\")]\npub mod y2023;\n#[cfg(feature = \"y2025\")]\npub mod y2025;\n";
+        assert_eq!(
+            insert_year_mod(lib_rs, 2024).unwrap(),
+            "#[cfg(feature = \"y2023\")]\npub mod y2023;\n#[cfg(feature = \"y2024\")]\npub mod y2024;\n#[cfg(feature = \"y2025\")]\npub mod y2025;\n"
+        );
+        assert!(insert_year_mod(lib_rs, 2025).is_err());
+    }
+
+    #[test]
+    fn new_year() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let src = root.join("src");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(src.join("y2024")).unwrap();
+        fs::write(src.join("y2024/mod.rs"), MOD_RS).unwrap();
+        fs::write(
+            src.join("lib.rs"),
+            "#[cfg(feature = \"y2023\")]\npub mod y2023;\n#[cfg(feature = \"y2024\")]\npub mod y2024;\n\npub mod answer;\n",
+        )
+        .unwrap();
+        fs::write(
+            src.join("registry.rs"),
+            "const YEARS: &[&[Day]] = &[\n    #[cfg(feature = \"y2023\")] crate::y2023::DAYS,\n    #[cfg(feature = \"y2024\")] crate::y2024::DAYS,\n];\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[features]\ndefault = [\"y2023\", \"y2024\"]\ny2023 = []\ny2024 = []\nembed-inputs = []\n",
+        )
+        .unwrap();
+
+        assert_eq!(new_day(&src, 2025, 1, "First").unwrap().len(), 5);
+        let mod_rs = fs::read_to_string(src.join("y2025/mod.rs")).unwrap();
+        assert!(mod_rs.contains("pub mod d1;"));
+        assert!(mod_rs.contains("day!(2025, 1, \"First\", d1::solve_pt1, d1::solve_pt2),"));
+        let lib_rs = fs::read_to_string(src.join("lib.rs")).unwrap();
+        assert!(lib_rs.contains(
+            "pub mod y2024;\n#[cfg(feature = \"y2025\")]\npub mod y2025;\n\npub mod answer;"
+        ));
+        let registry_rs = fs::read_to_string(src.join("registry.rs")).unwrap();
+        assert!(registry_rs.contains(
+            "crate::y2024::DAYS,\n    #[cfg(feature = \"y2025\")] crate::y2025::DAYS,\n];"
+        ));
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[features]\ndefault = [\"y2023\", \"y2024\", \"y2025\"]\ny2023 = []\ny2024 = []\ny2025 = []\nembed-inputs = []\n"
+        );
+
+        assert_eq!(new_day(&src, 2025, 2, "Second").unwrap().len(), 2);
+        assert!(new_day(&src, 2025, 2, "Second").is_err());
+        assert!(new_day(&src, 2025, 26, "Too Late").is_err());
+        assert_eq!(
+            fs::read_to_string(src.join("y2025/d2.rs")).unwrap(),
+            DAY_TEMPLATE
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use crate::answer::Answer;
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Self; 2] = [Self::One, Self::Two];
+
+    pub fn from_number(num: u32) -> Option<Self> {
+        match num {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u32 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Solves one part of a day's puzzle from its input.
+pub type Solver = fn(&str) -> Answer;
+
+/// Parses a day's input the same way its solvers do (discarding the result), so that parsing can be timed on its own.
+pub type Parser = fn(&str);
+
+/// A single day's puzzle, along with the solvers for whichever of its parts have been implemented.
+pub trait Solution: Sync {
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    fn title(&self) -> &'static str;
+
+    /// The day's input if it was embedded at compile-time (see `input::embedded`).
+    fn embedded_input(&self) -> Option<&'static str>;
+
+    /// Get the solver for `part`, or `None` if that part hasn't been implemented.
+    fn solver(&self, part: Part) -> Option<Solver>;
+
+    /// Get the day's parse step, or `None` if its solvers don't share one.
+    fn parser(&self) -> Option<Parser> {
+        return None;
+    }
+
+    fn implemented(&self, part: Part) -> bool {
+        return self.solver(part).is_some();
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        return Part::BOTH
+            .into_iter()
+            .filter(|&p| self.implemented(p))
+            .collect();
+    }
+}
+
+/// A `Solution` built from the `solve_pt*` functions of a day's module (see `day!`).
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub pt1: Option<Solver>,
+    pub pt2: Option<Solver>,
+    pub parse: Option<Parser>,
+    pub input: Option<&'static str>,
+}
+
+impl Solution for Day {
+    fn year(&self) -> u32 {
+        return self.year;
+    }
+
+    fn day(&self) -> u32 {
+        return self.day;
+    }
+
+    fn title(&self) -> &'static str {
+        return self.title;
+    }
+
+    fn embedded_input(&self) -> Option<&'static str> {
+        return self.input;
+    }
+
+    fn solver(&self, part: Part) -> Option<Solver> {
+        match part {
+            Part::One => self.pt1,
+            Part::Two => self.pt2,
+        }
+    }
+
+    fn parser(&self) -> Option<Parser> {
+        return self.parse;
+    }
+}
+
+/// Create a `Day` for a year's registry from its solver functions, which can return any type that converts into an `Answer`.
+/// The second solver may be left out if part 2 hasn't been implemented,
+/// and the function that both solvers parse the input with can be given after `; parse =`.
+#[macro_export]
+macro_rules! day {
+    ($year:literal, $day:literal, $title:literal, $pt1:path $(, $pt2:path)? $(; parse = $parse:path)?) => {
+        $crate::solution::Day {
+            year: $year,
+            day: $day,
+            title: $title,
+            pt1: Some(|input: &str| $pt1(input).into()),
+            pt2: $crate::day!(@part $($pt2)?),
+            parse: $crate::day!(@parse $($parse)?),
+            input: $crate::input::embedded($year, $day),
+        }
+    };
+    (@part $f:path) => {
+        Some(|input: &str| $f(input).into())
+    };
+    (@part) => {
+        None
+    };
+    (@parse $f:path) => {
+        Some(|input: &str| {
+            std::hint::black_box($f(input));
+        })
+    };
+    (@parse) => {
+        None
+    };
+}
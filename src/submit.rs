@@ -0,0 +1,480 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use advent_of_code::{
+    answer::Answer,
+    answers,
+    input::{self, InputSource},
+    registry,
+    runner::{self, Outcome},
+    solution::Part,
+};
+
+use crate::client::Client;
+
+/// The directory containing the log of every year's submitted answers, as "yYYYY.txt".
+/// These are specific to the account they were submitted from, so they aren't committed.
+pub const GUESSES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/guesses");
+
+/// How the site responded to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer is wrong, without a hint as to which way.
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, so this one wasn't checked.
+    RateLimited,
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel,
+    /// The response couldn't be understood.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        return matches!(self, Self::Incorrect | Self::TooHigh | Self::TooLow);
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Incorrect => "incorrect",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::RateLimited => "rate limited",
+            Self::WrongLevel => "wrong level",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return [
+            Self::Correct,
+            Self::Incorrect,
+            Self::TooHigh,
+            Self::TooLow,
+            Self::RateLimited,
+            Self::WrongLevel,
+            Self::Unknown,
+        ]
+        .into_iter()
+        .find(|v| v.name() == s)
+        .ok_or_else(|| format!("Unknown verdict '{}'!", s));
+    }
+}
+
+/// Parse the page returned after submitting an answer, returning its verdict along with the site's message (as plain text).
+pub fn parse_verdict(html: &str) -> (Verdict, String) {
+    // * The message is the page's only `<article>`, the rest of which is navigation.
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    let message = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    };
+    return (verdict, message);
+}
+
+/// A submitted answer and the site's verdict on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    /// When the answer was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u32,
+    pub part: Part,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+impl Display for Guess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day {}, part {}: {} ({})",
+            self.time, self.day, self.part, self.answer, self.verdict
+        )
+    }
+}
+
+/// Every answer submitted for a year's puzzles, in the order they were submitted.
+/// Stored as lines of "TIME day D, part P: ANSWER (VERDICT)", where empty lines and lines starting with '#' are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut guesses = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || format!("Invalid guess on line {}: '{}'!", i + 1, line);
+
+            let (time, rest) = line.split_once(' ').ok_or_else(invalid)?;
+            let (key, rest) = rest.split_once(':').ok_or_else(invalid)?;
+            let (day, part) = answers::parse_key(key).ok_or_else(invalid)?;
+            let (answer, verdict) = rest
+                .strip_suffix(')')
+                .and_then(|rest| rest.rsplit_once(" ("))
+                .ok_or_else(invalid)?;
+            guesses.push(Guess {
+                time: time.parse().map_err(|_| invalid())?,
+                day,
+                part,
+                answer: answer.parse()?,
+                verdict: verdict.parse()?,
+            });
+        }
+        return Ok(Self { guesses });
+    }
+
+    /// The guesses made for one part of a day.
+    pub fn of(&self, day: u32, part: Part) -> impl Iterator<Item = &Guess> {
+        return self
+            .guesses
+            .iter()
+            .filter(move |g| g.day == day && g.part == part);
+    }
+
+    /// What the guesses made for one part of a day reveal about its answer.
+    pub fn bounds(&self, day: u32, part: Part) -> Bounds {
+        let mut bounds = Bounds::default();
+        for guess in self.of(day, part) {
+            let num = guess.answer.as_number();
+            match guess.verdict {
+                Verdict::Correct => bounds.correct = Some(guess.answer.clone()),
+                Verdict::TooLow => bounds.low = bounds.low.max(num),
+                Verdict::TooHigh => {
+                    bounds.high = match (bounds.high, num) {
+                        (Some(high), Some(num)) => Some(high.min(num)),
+                        (high, num) => high.or(num),
+                    }
+                }
+                _ => (),
+            }
+            if guess.verdict.is_wrong() && !bounds.wrong.contains(&guess.answer) {
+                bounds.wrong.push(guess.answer.clone());
+            }
+        }
+        return bounds;
+    }
+}
+
+/// What's known about the answer to a part from its previous guesses.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// The highest answer that was too low.
+    pub low: Option<i128>,
+    /// The lowest answer that was too high.
+    pub high: Option<i128>,
+    /// Every answer that was wrong, including those that were too low or too high.
+    pub wrong: Vec<Answer>,
+    pub correct: Option<Answer>,
+}
+
+impl Bounds {
+    /// Check whether `answer` could still be correct, returning why not if it can't.
+    pub fn check(&self, answer: &Answer) -> Result<(), String> {
+        if let Some(correct) = &self.correct {
+            return Err(format!("This part was already solved with {}!", correct));
+        }
+        if self.wrong.contains(answer) {
+            return Err(format!("{} was already guessed, and it's wrong!", answer));
+        }
+        let Some(num) = answer.as_number() else {
+            return Ok(());
+        };
+        if let Some(low) = self.low.filter(|&low| num <= low) {
+            return Err(format!("{} is too low, since {} already was!", num, low));
+        }
+        if let Some(high) = self.high.filter(|&high| num >= high) {
+            return Err(format!("{} is too high, since {} already was!", num, high));
+        }
+        return Ok(());
+    }
+
+    /// The range of numbers that the answer must be in, if any bounds are known,
+    /// or why the guesses are contradictory if no number is left between them.
+    pub fn window(&self) -> Result<Option<String>, String> {
+        return match (self.low, self.high) {
+            (Some(low), Some(high)) if low + 1 > high - 1 => Err(format!(
+                "The guesses contradict each other, since {} was too low but {} was too high!",
+                low, high
+            )),
+            (Some(low), Some(high)) => Ok(Some(format!("{}..={}", low + 1, high - 1))),
+            (Some(low), None) => Ok(Some(format!("{}..", low + 1))),
+            (None, Some(high)) => Ok(Some(format!("..={}", high - 1))),
+            (None, None) => Ok(None),
+        };
+    }
+}
+
+pub fn log_path(year: u32) -> PathBuf {
+    return PathBuf::from(GUESSES_DIR).join(format!("y{}.txt", year));
+}
+
+/// Load the guesses made for `year`, which are empty if none have been logged.
+pub fn load_log(year: u32) -> Result<GuessLog, String> {
+    let path = log_path(year);
+    match fs::read_to_string(&path) {
+        Ok(s) => GuessLog::parse(&s).map_err(|err| format!("{} ({})", err, path.display())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(GuessLog::default()),
+        Err(err) => Err(format!("Failed to read '{}': {}", path.display(), err)),
+    }
+}
+
+/// Append `guess` to the log of `year`.
+pub fn log_guess(year: u32, guess: &Guess) -> Result<(), String> {
+    let path = log_path(year);
+    let failed = |err: io::Error| format!("Failed to write '{}': {}", path.display(), err);
+    fs::create_dir_all(GUESSES_DIR).map_err(failed)?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(failed)?;
+    return writeln!(file, "{}", guess).map_err(failed);
+}
+
+/// The current time in seconds since the Unix epoch.
+pub fn now() -> u64 {
+    return SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+}
+
+/// Run a part on its input (from `source`) and submit its answer, logging the guess and recording the answer if it's correct.
+/// Answers that are known to be wrong from previous guesses are refused, since every wrong guess makes the site wait longer before the next.
+pub fn run(
+    year: u32,
+    day: u32,
+    part: Part,
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let solution = registry::find(year, day)
+        .ok_or_else(|| format!("No solution found for {} day {}!", year, day))?;
+    let solver = solution
+        .solver(part)
+        .ok_or_else(|| format!("{} day {}, part {} isn't implemented!", year, day, part))?;
+    let input: Arc<str> = input::load(solution, source)
+        .map_err(|err| err.to_string())?
+        .into();
+    let answer = match runner::execute_with_timeout(solver, &input, timeout) {
+        Outcome::Solved(answer) => answer,
+        outcome => return Err(format!("{} day {}, part {}: {}", year, day, part, outcome)),
+    };
+
+    let mut log = load_log(year)?;
+    let contradiction = |err| {
+        format!(
+            "{} Check the guesses in '{}'.",
+            err,
+            log_path(year).display()
+        )
+    };
+    let bounds = log.bounds(day, part);
+    if let Err(reason) = bounds.check(&answer) {
+        let mut err = format!("Refusing to submit {}: {}", answer, reason);
+        match bounds.window() {
+            Ok(Some(window)) => err.push_str(&format!("\nThe answer must be in {}.", window)),
+            Ok(None) => {}
+            Err(reason) => err.push_str(&format!("\n{}", contradiction(reason))),
+        }
+        return Err(err);
+    }
+
+    println!(
+        "Submitting {} for {} day {}, part {}...",
+        answer, year, day, part
+    );
+    let (verdict, msg) = Client::from_env()?.submit(year, day, part, &answer)?;
+    println!("{}\nVerdict: {}", msg, verdict);
+
+    let guess = Guess {
+        time: now(),
+        day,
+        part,
+        answer,
+        verdict,
+    };
+    log_guess(year, &guess)?;
+    if verdict != Verdict::Correct {
+        log.guesses.push(guess);
+        return Err(match log.bounds(day, part).window() {
+            Ok(Some(window)) => format!("The answer must be in {}.", window),
+            Ok(None) => format!("The answer wasn't accepted ({}).", verdict),
+            Err(err) => contradiction(err),
+        });
+    }
+    answers::record(year, day, part, &guess.answer)?;
+    println!(
+        "Recorded the answer in '{}'.",
+        answers::path(year).display()
+    );
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let page = |msg: &str| {
+            format!(
+                "<html><main>\n<article><p>{}</p></article>\n</main><a href=\"/\">[Return]</a></html>",
+                msg
+            )
+        };
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            (
+                Verdict::Correct,
+                "That's the right answer! You are one gold star closer.".to_string()
+            )
+        );
+        let cases = [
+            (
+                "That's not the right answer; your answer is too high.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck, ...",
+                Verdict::Incorrect,
+            ),
+            (
+                "You gave an answer too recently. You have 41s left to wait.",
+                Verdict::RateLimited,
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Verdict::WrongLevel,
+            ),
+            ("Something else entirely.", Verdict::Unknown),
+        ];
+        for (msg, verdict) in cases {
+            assert_eq!(parse_verdict(&page(msg)).0, verdict);
+        }
+    }
+
+    #[test]
+    fn log() {
+        let log = GuessLog::parse(
+            "# Guesses\n1733011200 day 1, part 1: 1234 (too low)\n1733011300 day 17, part 1: 1,0,3 (correct)\n",
+        )
+        .unwrap();
+        assert_eq!(
+            log.guesses[1],
+            Guess {
+                time: 1733011300,
+                day: 17,
+                part: Part::One,
+                answer: Answer::Text("1,0,3".to_string()),
+                verdict: Verdict::Correct,
+            }
+        );
+        assert_eq!(log.of(1, Part::One).count(), 1);
+        assert_eq!(
+            GuessLog::parse(&log.guesses[0].to_string())
+                .unwrap()
+                .guesses[0],
+            log.guesses[0]
+        );
+        assert!(GuessLog::parse("1733011200 day 1, part 1: 1234").is_err());
+        assert!(GuessLog::parse("1733011200 day 1, part 1: 1234 (maybe)").is_err());
+    }
+
+    #[test]
+    fn bounds() {
+        let log = GuessLog::parse(
+            "1 day 1, part 1: 100 (too low)\n2 day 1, part 1: 500 (too high)\n3 day 1, part 1: 120 (too low)\n\
+             4 day 1, part 1: 300 (incorrect)\n5 day 1, part 1: 400 (too high)\n6 day 1, part 1: 250 (rate limited)\n\
+             7 day 1, part 2: abc (correct)",
+        )
+        .unwrap();
+        let bounds = log.bounds(1, Part::One);
+        assert_eq!((bounds.low, bounds.high), (Some(120), Some(400)));
+        assert_eq!(bounds.window(), Ok(Some("121..=399".to_string())));
+        assert!(bounds.check(&Answer::Number(250)).is_ok());
+        assert!(bounds.check(&Answer::Number(300)).is_err());
+        assert!(bounds.check(&Answer::Number(120)).is_err());
+        assert!(bounds.check(&Answer::Number(110)).is_err());
+        assert!(bounds.check(&Answer::Number(400)).is_err());
+        assert!(bounds.check(&Answer::Text("1,2".to_string())).is_ok());
+
+        assert!(log.bounds(1, Part::Two).check(&"abd".into()).is_err());
+        assert_eq!(log.bounds(2, Part::One), Bounds::default());
+        assert_eq!(Bounds::default().window(), Ok(None));
+
+        // * Bounds that leave no number between them can only come from contradictory guesses.
+        let window = |low, high| {
+            let bounds = Bounds {
+                low: Some(low),
+                high: Some(high),
+                ..Bounds::default()
+            };
+            return bounds.window();
+        };
+        assert_eq!(window(120, 122), Ok(Some("121..=121".to_string())));
+        assert!(window(120, 121).is_err());
+        assert!(window(400, 120).is_err());
+    }
+}
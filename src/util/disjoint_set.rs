@@ -0,0 +1,133 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// A partition of the elements `0..len` into disjoint sets (a.k.a. union-find), which starts with each element in a set of its own.
+/// Sets are merged by size and their trees are compressed as they're searched, so each operation takes (nearly) constant time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointSet {
+    /// The parent of each element, where the root of each set is its own parent.
+    parents: Vec<usize>,
+    /// The size of each set, which is only kept up to date for roots.
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        return Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            count: len,
+        };
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        return self.parents.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.parents.is_empty();
+    }
+
+    /// The number of sets.
+    pub fn count(&self) -> usize {
+        return self.count;
+    }
+
+    /// The root of the set containing `x`, which is the same for every element of the set.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // * Point every element on the way straight to the root, so that it's found immediately next time.
+        let mut current = x;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        return root;
+    }
+
+    /// Merge the sets containing `a` and `b`, returning `false` if they were already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // * The smaller set is added to the larger set, which keeps the trees shallow.
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.count -= 1;
+        return true;
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        return self.find(a) == self.find(b);
+    }
+
+    /// The size of the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        return self.sizes[root];
+    }
+
+    /// The size of every set, in no particular order.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        return (0..self.len())
+            .filter(|&x| self.parents[x] == x)
+            .map(|root| self.sizes[root]);
+    }
+
+    /// The sizes of the `k` largest sets, from largest to smallest.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut largest = BinaryHeap::with_capacity(k + 1);
+        for size in self.sizes() {
+            largest.push(Reverse(size));
+            if largest.len() > k {
+                largest.pop();
+            }
+        }
+        return largest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(size)| size)
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions() {
+        let mut set = DisjointSet::new(8);
+        assert_eq!(set.count(), 8);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert!(set.union(5, 6));
+        assert_eq!(set.count(), 4);
+
+        assert!(set.same(0, 3));
+        assert!(!set.same(3, 5));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(4), 1);
+        let mut sizes = set.sizes().collect::<Vec<_>>();
+        sizes.sort();
+        assert_eq!(sizes, [1, 1, 2, 4]);
+        assert_eq!(set.largest(3), [4, 2, 1]);
+        assert_eq!(set.largest(10), [4, 2, 1, 1]);
+        assert_eq!(set.largest(0), []);
+
+        for x in 1..8 {
+            set.union(x - 1, x);
+        }
+        assert_eq!(set.count(), 1);
+        assert_eq!(set.largest(2), [8]);
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use super::vec2::Vec2;
+
+/// A position within a `Grid`, which is signed so that stepping off the edge of the grid can be checked for.
+pub type Pos = Vec2<isize>;
+
+/// The operations shared by `Grid` and `SparseGrid`, for code that works with either.
+/// A position without a cell is outside of a `Grid`, but is an empty cell of a `SparseGrid` (which can be set).
+/// Their `parse` functions differ in the same way, since only a `SparseGrid` can leave a character's cell empty.
+pub trait GridLike {
+    type Cell;
+
+    /// The cell at `pos`, or `None` if there isn't one.
+    fn get(&self, pos: Pos) -> Option<&Self::Cell>;
+
+    /// Replace the cell at `pos`, returning the previous cell (or `None` if there wasn't one).
+    /// A `Grid` is left unchanged if `pos` is outside of it.
+    fn set(&mut self, pos: Pos, value: Self::Cell) -> Option<Self::Cell>;
+
+    /// The orthogonally adjacent cells of `pos` (in the same order as `Dir::ALL`).
+    fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &Self::Cell)>;
+
+    /// The orthogonally and diagonally adjacent cells of `pos`.
+    fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &Self::Cell)>;
+
+    /// The smallest rectangle (from `min` to `max`, inclusive) that contains every cell, or `None` if there aren't any.
+    fn bounds(&self) -> Option<(Pos, Pos)>;
+}
+
+/// A dense rectangular grid of cells (e.g. the characters of an input), stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells, which are given row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid size mismatch!");
+        return Self {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    /// Create a grid by calling `f` with the position of each cell.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(Pos::new(x as isize, y as isize)));
+            }
+        }
+        return Self::new(width, height, cells);
+    }
+
+    /// Parse each line of `input` as a row, using `f` to convert each character into a cell.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let line_width = cells.len() - len;
+            assert_eq!(
+                *width.get_or_insert(line_width),
+                line_width,
+                "Grid rows must all be the same length!"
+            );
+            height += 1;
+        }
+        return Self::new(width.unwrap_or(0), height, cells);
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /// The position one past the bottom-right corner of the grid, i.e. its width and height.
+    pub fn size(&self) -> Pos {
+        return Pos::new(self.width as isize, self.height as isize);
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        return self.index_of(pos).is_some();
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        if x < self.width && y < self.height {
+            return Some(y * self.width + x);
+        }
+        return None;
+    }
+
+    fn pos_of(&self, i: usize) -> Pos {
+        return Pos::new((i % self.width) as isize, (i / self.width) as isize);
+    }
+
+    /// The cell at `pos`, or `None` if it's outside of the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        return self.index_of(pos).map(|i| &self.cells[i]);
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        return self.index_of(pos).map(|i| &mut self.cells[i]);
+    }
+
+    /// Replace the cell at `pos`, returning the previous cell (or `None` if `pos` is outside of the grid, in which case nothing is changed).
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        return self.get_mut(pos).map(|cell| std::mem::replace(cell, value));
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        return (0..self.cells.len())
+            .map(move |i| Pos::new((i % width) as isize, (i / width) as isize));
+    }
+
+    /// Every cell in the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.pos_of(i), cell));
+    }
+
+    /// The position of the first cell (row by row) that matches `pred`.
+    pub fn find(&self, pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        return self.cells.iter().position(pred).map(|i| self.pos_of(i));
+    }
+
+    /// The positions of every cell that matches `pred`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        return self
+            .iter()
+            .filter(move |(_, cell)| pred(cell))
+            .map(|(pos, _)| pos);
+    }
+
+    /// The orthogonally adjacent cells of `pos` that are within the grid (in the same order as `Dir::ALL`).
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return pos.neighbours4().filter_map(|n| Some((n, self.get(n)?)));
+    }
+
+    /// The orthogonally and diagonally adjacent cells of `pos` that are within the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return pos.neighbours8().filter_map(|n| Some((n, self.get(n)?)));
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(|y| self.row(y));
+    }
+
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Grid column out of bounds!");
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    /// The cells from `start` (inclusive) in steps of `step`, until the edge of the grid is reached.
+    /// e.g. a step of (1, 1) gives a diagonal down and to the right.
+    pub fn ray(&self, start: Pos, step: Pos) -> impl Iterator<Item = &T> {
+        assert_ne!(step, Pos::ZERO, "A ray's step can't be zero!");
+        let mut pos = start;
+        return std::iter::from_fn(move || {
+            let cell = self.get(pos)?;
+            pos += step;
+            return Some(cell);
+        });
+    }
+
+    /// Convert each cell into another type.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid::new(self.width, self.height, self.cells.iter().map(f).collect());
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid where every cell is `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        return Self::new(width, height, vec![value; width * height]);
+    }
+
+    /// Swap the rows and columns of the grid (i.e. mirror it along its main diagonal).
+    pub fn transpose(&self) -> Self {
+        return Self::from_fn(self.height, self.width, |pos| {
+            self[Pos::new(pos.y, pos.x)].clone()
+        });
+    }
+
+    /// Rotate the grid 90° clockwise (as it would appear on the page).
+    pub fn rotate_cw(&self) -> Self {
+        let h = self.height as isize;
+        return Self::from_fn(self.height, self.width, |pos| {
+            self[Pos::new(pos.y, h - 1 - pos.x)].clone()
+        });
+    }
+
+    /// Rotate the grid 90° anticlockwise (as it would appear on the page).
+    pub fn rotate_ccw(&self) -> Self {
+        let w = self.width as isize;
+        return Self::from_fn(self.height, self.width, |pos| {
+            self[Pos::new(w - 1 - pos.y, pos.x)].clone()
+        });
+    }
+}
+
+impl<T> GridLike for Grid<T> {
+    type Cell = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        return Grid::get(self, pos);
+    }
+
+    fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        return Grid::set(self, pos, value);
+    }
+
+    fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return Grid::neighbours4(self, pos);
+    }
+
+    fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return Grid::neighbours8(self, pos);
+    }
+
+    fn bounds(&self) -> Option<(Pos, Pos)> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        return Some((Pos::ZERO, self.size() - Pos::new(1, 1)));
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        return self.get(pos).expect("Grid position out of bounds!");
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        return self.get_mut(pos).expect("Grid position out of bounds!");
+    }
+}
+
+/// Prints each row on its own line, with every cell printed next to each other (i.e. the same layout that the grid was parsed from).
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::sparse_grid::SparseGrid;
+
+    const EXAMPLE: &str = "\
+#.S
+.##
+E..
+#.#";
+
+    #[test]
+    fn parsing() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.to_string(), EXAMPLE);
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Pos::new(2, 0)));
+        assert_eq!(grid.find(|&c| c == 'E'), Some(Pos::new(0, 2)));
+        assert_eq!(grid.find(|&c| c == '@'), None);
+        assert_eq!(grid.find_all(|&c| c == '#').count(), 5);
+        assert_eq!(grid.get(Pos::new(1, 1)), Some(&'#'));
+        assert_eq!(grid.get(Pos::new(-1, 0)), None);
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.positions().last(), Some(Pos::new(2, 3)));
+
+        let walls = grid.map(|&c| c == '#');
+        assert_eq!(
+            walls
+                .neighbours4(Pos::new(0, 0))
+                .filter(|(_, &w)| w)
+                .count(),
+            0
+        );
+        assert_eq!(
+            walls
+                .neighbours8(Pos::new(1, 2))
+                .filter(|(_, &w)| w)
+                .count(),
+            4
+        );
+        assert_eq!(walls.neighbours8(Pos::new(0, 0)).count(), 3);
+    }
+
+    /// The number of cells adjacent to `pos` that match `pred`, for any kind of grid.
+    fn count_neighbours<G: GridLike>(grid: &G, pos: Pos, pred: impl Fn(&G::Cell) -> bool) -> usize {
+        return grid.neighbours8(pos).filter(|(_, cell)| pred(cell)).count();
+    }
+
+    #[test]
+    fn grid_like() {
+        let mut grid = Grid::parse(EXAMPLE, |c| c);
+        let mut sparse = SparseGrid::parse(EXAMPLE, |c| (c != '.').then_some(c));
+        assert_eq!(GridLike::bounds(&grid), Some((Pos::ZERO, Pos::new(2, 3))));
+        assert_eq!(GridLike::bounds(&sparse), GridLike::bounds(&grid));
+        assert_eq!(Grid::<char>::new(0, 0, vec![]).bounds(), None);
+
+        for pos in [Pos::new(1, 1), Pos::new(0, 0), Pos::new(2, 3)] {
+            assert_eq!(
+                count_neighbours(&grid, pos, |&c| c == '#'),
+                count_neighbours(&sparse, pos, |&c| c == '#')
+            );
+        }
+        // * Setting a position without a cell only adds one to a `SparseGrid`.
+        assert_eq!(GridLike::set(&mut grid, Pos::new(3, 0), '#'), None);
+        assert_eq!(GridLike::set(&mut sparse, Pos::new(3, 0), '#'), None);
+        assert_eq!(GridLike::get(&grid, Pos::new(3, 0)), None);
+        assert_eq!(GridLike::get(&sparse, Pos::new(3, 0)), Some(&'#'));
+    }
+
+    #[test]
+    fn slicing() {
+        let mut grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!(grid.row(1), ['.', '#', '#']);
+        assert_eq!(grid.col(2).collect::<String>(), "S#.#");
+        assert_eq!(
+            grid.ray(Pos::new(0, 0), Pos::new(1, 1)).collect::<String>(),
+            "##."
+        );
+        assert_eq!(
+            grid.ray(Pos::new(2, 0), Pos::new(-1, 1))
+                .collect::<String>(),
+            "S#E"
+        );
+
+        assert_eq!(grid.transpose().to_string(), "#.E#\n.#..\nS#.#");
+        assert_eq!(grid.rotate_cw().to_string(), "#E.#\n..#.\n#.#S");
+        assert_eq!(grid.rotate_ccw().to_string(), "S#.#\n.#..\n#.E#");
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.rotate_ccw().rotate_ccw());
+
+        assert_eq!(grid.set(Pos::new(2, 0), '.'), Some('S'));
+        assert_eq!(grid.set(Pos::new(0, 4), '.'), None);
+        grid[Pos::new(0, 2)] = 'S';
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Pos::new(0, 2)));
+    }
+}
@@ -0,0 +1,222 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use super::{num::Num, vec3::Vec3};
+
+/// A k-d tree of 3D points, for finding the points nearest to a position without checking every point.
+/// Distances are squared (see `Vec3::dist_squared`), and points are referred to by their index in the slice the tree was built from.
+#[derive(Debug, Clone)]
+pub struct KdTree<T> {
+    points: Vec<Vec3<T>>,
+    /// The indices of `points`, arranged so that each range of the tree has its splitting point in the middle (see `build`).
+    nodes: Vec<usize>,
+}
+
+impl<T: Num> KdTree<T> {
+    pub fn new(points: &[Vec3<T>]) -> Self {
+        let mut nodes = (0..points.len()).collect::<Vec<_>>();
+        Self::build(points, &mut nodes, 0);
+        return Self {
+            points: points.to_vec(),
+            nodes,
+        };
+    }
+
+    // * Each range of `nodes` is split at its middle point along `axis` (which cycles through x, y and z with each level),
+    // * with every point before it being no further along that axis, and every point after it being no closer.
+    fn build(points: &[Vec3<T>], nodes: &mut [usize], axis: usize) {
+        if nodes.len() <= 1 {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+        let (before, after) = nodes.split_at_mut(mid);
+        Self::build(points, before, (axis + 1) % 3);
+        Self::build(points, &mut after[1..], (axis + 1) % 3);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.points.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.points.is_empty();
+    }
+
+    pub fn points(&self) -> &[Vec3<T>] {
+        return &self.points;
+    }
+
+    /// The `k` points nearest to `query` as (distance, index), from nearest to furthest (with ties broken by index).
+    pub fn nearest(&self, query: Vec3<T>, k: usize) -> Vec<(T, usize)> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(query, k, &self.nodes, 0, &mut best);
+        }
+        return best.into_sorted_vec();
+    }
+
+    /// Add any points of `nodes` that are nearer than the furthest of the `k` `best` points found so far.
+    fn search(
+        &self,
+        query: Vec3<T>,
+        k: usize,
+        nodes: &[usize],
+        axis: usize,
+        best: &mut BinaryHeap<(T, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let point = self.points[nodes[mid]];
+        best.push((query.dist_squared(point), nodes[mid]));
+        if best.len() > k {
+            best.pop();
+        }
+
+        let (near, far) = if query[axis] < point[axis] {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+        let next_axis = (axis + 1) % 3;
+        self.search(query, k, near, next_axis, best);
+        // * The far side can only have a nearer point if the splitting plane itself is nearer than the furthest point found so far.
+        let plane = query[axis].abs_diff(point[axis]);
+        if best.len() < k || plane * plane <= best.peek().unwrap().0 {
+            self.search(query, k, far, next_axis, best);
+        }
+    }
+
+    /// Every pair of different points as (distance, a, b) where `a < b`, from nearest to furthest (with ties broken by `a` then `b`).
+    /// Pairs are only found as they're needed, so taking the nearest few pairs doesn't require checking every pair.
+    pub fn pairs(&self) -> Pairs<'_, T> {
+        let mut pairs = Pairs {
+            tree: self,
+            neighbours: vec![Vec::new(); self.len()],
+            heap: BinaryHeap::with_capacity(self.len()),
+        };
+        for a in 0..self.len() {
+            pairs.push_neighbour(a, 0);
+        }
+        return pairs;
+    }
+
+    /// Every pair of different points that are at most `max_dist` apart, as in `pairs`.
+    pub fn pairs_within(&self, max_dist: T) -> impl Iterator<Item = (T, usize, usize)> + '_ {
+        return self
+            .pairs()
+            .take_while(move |&(dist, _, _)| dist <= max_dist);
+    }
+}
+
+/// The iterator returned by `KdTree::pairs`.
+// ? Each point has a stream of its neighbours from nearest to furthest, which are merged by always taking the nearest of the streams' next neighbours.
+// ? Each pair appears in both of its points' streams, so it's only yielded from the stream of the point with the smaller index.
+#[derive(Debug, Clone)]
+pub struct Pairs<'a, T> {
+    tree: &'a KdTree<T>,
+    /// The nearest neighbours found so far for each point, which are searched for again (twice as many) once they've been used up.
+    neighbours: Vec<Vec<(T, usize)>>,
+    /// The next neighbour from each point's stream, as (distance, point, neighbour, index into `neighbours`).
+    heap: BinaryHeap<Reverse<(T, usize, usize, usize)>>,
+}
+
+impl<T: Num> Pairs<'_, T> {
+    /// The number of neighbours each point starts with.
+    const INITIAL_NEIGHBOURS: usize = 8;
+
+    /// Add the `i`th nearest neighbour of point `a` (other than itself) to the heap, if there is one.
+    fn push_neighbour(&mut self, a: usize, mut i: usize) {
+        let len = self.tree.len();
+        loop {
+            let neighbours = &mut self.neighbours[a];
+            if i == neighbours.len() {
+                if i == len {
+                    // * Every other point has already been paired with `a`.
+                    return;
+                }
+                let k = usize::min(len, usize::max(2 * i, Self::INITIAL_NEIGHBOURS));
+                *neighbours = self.tree.nearest(self.tree.points[a], k);
+            }
+            let (dist, b) = neighbours[i];
+            if b != a {
+                self.heap.push(Reverse((dist, a, b, i)));
+                return;
+            }
+            i += 1;
+        }
+    }
+}
+
+impl<T: Num> Iterator for Pairs<'_, T> {
+    type Item = (T, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((dist, a, b, i)) = self.heap.pop()?;
+            self.push_neighbour(a, i + 1);
+            if a < b {
+                return Some((dist, a, b));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A few hundred points scattered (deterministically) around a small space, so that there are plenty of duplicates and ties.
+    fn scattered_points() -> Vec<Vec3<i64>> {
+        let mut seed = 12345u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            return (seed >> 33) as i64 % 20 - 10;
+        };
+        return (0..300)
+            .map(|_| Vec3::new(next(), next(), next()))
+            .collect();
+    }
+
+    #[test]
+    fn nearest() {
+        let points = scattered_points();
+        let tree = KdTree::new(&points);
+        for query in [Vec3::ZERO, Vec3::new(3, -7, 12), points[42]] {
+            let mut expected = points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| (query.dist_squared(*p), i))
+                .collect::<Vec<_>>();
+            expected.sort();
+            for k in [0, 1, 5, 64, 300, 1000] {
+                assert_eq!(tree.nearest(query, k), expected[..k.min(300)]);
+            }
+        }
+        assert_eq!(KdTree::<i64>::new(&[]).nearest(Vec3::ZERO, 3), []);
+    }
+
+    #[test]
+    fn pairs() {
+        let points = scattered_points();
+        let tree = KdTree::new(&points);
+        let mut expected = Vec::new();
+        for a in 0..points.len() {
+            for b in (a + 1)..points.len() {
+                expected.push((points[a].dist_squared(points[b]), a, b));
+            }
+        }
+        expected.sort();
+        assert_eq!(tree.pairs().collect::<Vec<_>>(), expected);
+
+        let within = expected.iter().take_while(|p| p.0 <= 10).count();
+        assert_eq!(
+            tree.pairs_within(10).collect::<Vec<_>>(),
+            expected[..within]
+        );
+        assert_eq!(KdTree::new(&points[..1]).pairs().next(), None);
+    }
+}
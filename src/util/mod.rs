@@ -0,0 +1,9 @@
+//! Types shared between the solutions of several days, such as positions on a 2D map.
+
+pub mod disjoint_set;
+pub mod grid;
+pub mod kd_tree;
+pub mod num;
+pub mod sparse_grid;
+pub mod vec2;
+pub mod vec3;
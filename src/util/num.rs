@@ -0,0 +1,66 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+/// The integer types that the shared position types (e.g. `Vec2`) can be made of.
+pub trait Num:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The (non-negative) difference between `self` and `other`, which can't overflow for unsigned types.
+    fn abs_diff(self, other: Self) -> Self {
+        return if self > other {
+            self - other
+        } else {
+            other - self
+        };
+    }
+
+    /// The remainder of `self / rhs`, which is never negative (unlike `%`).
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let rem = self % rhs;
+        return if rem < Self::ZERO { rem + rhs } else { rem };
+    }
+}
+
+/// The integer types that can be negative, which are needed for directions and rotations.
+pub trait Signed: Num + Neg<Output = Self> {}
+
+macro_rules! impl_num {
+    ($($t:ty),*) => {
+        $(
+            impl Num for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+
+impl_num!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
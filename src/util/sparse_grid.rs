@@ -0,0 +1,272 @@
+use std::{collections::HashMap, fmt::Display, ops::Index};
+
+use super::grid::{GridLike, Pos};
+
+/// A grid that only stores its occupied cells, for maps that are mostly empty or have no fixed size.
+/// Has the same API as `Grid` where it makes sense (see `GridLike`), except that empty cells are `None` rather than out of bounds.
+///
+/// It can also wrap around a rectangle of some size (i.e. a torus), so that every position outside of the rectangle refers to a cell within it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    /// The size of the rectangle that positions wrap around, if any.
+    wrap: Option<Pos>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create an empty grid with no bounds.
+    pub fn new() -> Self {
+        return Self {
+            cells: HashMap::new(),
+            wrap: None,
+        };
+    }
+
+    /// Create an empty grid that wraps around the rectangle from zero to `size` (exclusive).
+    pub fn wrapping(size: Pos) -> Self {
+        assert!(size.x > 0 && size.y > 0, "A wrapping grid can't be empty!");
+        return Self {
+            cells: HashMap::new(),
+            wrap: Some(size),
+        };
+    }
+
+    /// Parse each line of `input` as a row, using `f` to convert each character into a cell (or `None` if it's empty).
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Self {
+        let mut grid = Self::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(cell) = f(c) {
+                    grid.set(Pos::new(x as isize, y as isize), cell);
+                }
+            }
+        }
+        return grid;
+    }
+
+    /// The position that `pos` refers to, i.e. `pos` wrapped around the grid if it wraps.
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        return match self.wrap {
+            Some(size) => pos.rem_euclid(size),
+            None => pos,
+        };
+    }
+
+    /// The number of occupied cells.
+    pub fn len(&self) -> usize {
+        return self.cells.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cells.is_empty();
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        return self.cells.contains_key(&self.wrap(pos));
+    }
+
+    /// The cell at `pos`, or `None` if it's empty.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        return self.cells.get(&self.wrap(pos));
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let pos = self.wrap(pos);
+        return self.cells.get_mut(&pos);
+    }
+
+    /// Replace the cell at `pos`, returning the previous cell (or `None` if it was empty).
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        let pos = self.wrap(pos);
+        return self.cells.insert(pos, value);
+    }
+
+    /// Empty the cell at `pos`, returning the previous cell (or `None` if it was already empty).
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        let pos = self.wrap(pos);
+        return self.cells.remove(&pos);
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Every occupied position, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        return self.cells.keys().copied();
+    }
+
+    /// Every occupied cell along with its position, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        return self.cells.iter().map(|(&pos, cell)| (pos, cell));
+    }
+
+    /// The position of any cell that matches `pred`.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        return self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos);
+    }
+
+    /// The positions of every cell that matches `pred`, in no particular order.
+    pub fn find_all<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        return self
+            .iter()
+            .filter(move |(_, cell)| pred(cell))
+            .map(|(pos, _)| pos);
+    }
+
+    /// The occupied cells that are orthogonally adjacent to `pos` (in the same order as `Dir::ALL`).
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return pos
+            .neighbours4()
+            .filter_map(|n| self.cells.get_key_value(&self.wrap(n)))
+            .map(|(&n, cell)| (n, cell));
+    }
+
+    /// The occupied cells that are orthogonally or diagonally adjacent to `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return pos
+            .neighbours8()
+            .filter_map(|n| self.cells.get_key_value(&self.wrap(n)))
+            .map(|(&n, cell)| (n, cell));
+    }
+
+    /// The smallest rectangle (from `min` to `max`, inclusive) that contains every occupied cell, or `None` if there aren't any.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.positions();
+        let first = positions.next()?;
+        return Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }));
+    }
+
+    /// Convert each occupied cell into another type, keeping the same wrapping.
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> SparseGrid<U> {
+        return SparseGrid {
+            cells: self.iter().map(|(pos, cell)| (pos, f(cell))).collect(),
+            wrap: self.wrap,
+        };
+    }
+}
+
+impl<T> GridLike for SparseGrid<T> {
+    type Cell = T;
+
+    fn get(&self, pos: Pos) -> Option<&T> {
+        return SparseGrid::get(self, pos);
+    }
+
+    fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        return SparseGrid::set(self, pos, value);
+    }
+
+    fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return SparseGrid::neighbours4(self, pos);
+    }
+
+    fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        return SparseGrid::neighbours8(self, pos);
+    }
+
+    fn bounds(&self) -> Option<(Pos, Pos)> {
+        return SparseGrid::bounds(self);
+    }
+}
+
+impl<T> Index<Pos> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        return self.get(pos).expect("Grid position is empty!");
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        return Self {
+            cells: HashMap::from_iter(iter),
+            wrap: None,
+        };
+    }
+}
+
+/// Prints the same layout as `Grid`, with each empty cell printed as '.'.
+/// A wrapping grid prints its whole rectangle, while any other grid prints the bounds of its occupied cells.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min, max) = match self.wrap {
+            Some(size) => (Pos::ZERO, size - Pos::new(1, 1)),
+            None => match self.bounds() {
+                Some(bounds) => bounds,
+                None => return Ok(()),
+            },
+        };
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.cells.get(&Pos::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse() {
+        let mut grid = SparseGrid::parse("..#\n@..\n.#.", |c| (c != '.').then_some(c));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.find(|&c| c == '@'), Some(Pos::new(0, 1)));
+        assert_eq!(grid.get(Pos::new(2, 0)), Some(&'#'));
+        assert_eq!(grid.get(Pos::new(1, 0)), None);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 2);
+
+        // * Unlike `Grid`, cells can be added anywhere.
+        assert_eq!(grid.set(Pos::new(-1, 4), '#'), None);
+        assert_eq!(grid.bounds(), Some((Pos::new(-1, 0), Pos::new(2, 4))));
+        assert_eq!(grid.remove(Pos::new(0, 1)), Some('@'));
+        assert_eq!(grid.to_string(), "...#\n....\n..#.\n....\n#...");
+
+        grid.clear();
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+    }
+
+    #[test]
+    fn wrapping() {
+        let mut grid = SparseGrid::wrapping(Pos::new(3, 2));
+        grid.set(Pos::new(-1, 0), 1);
+        assert_eq!(grid.get(Pos::new(2, 0)), Some(&1));
+        assert_eq!(grid.get(Pos::new(5, 4)), Some(&1));
+        assert!(grid.contains(Pos::new(-4, -2)));
+        assert_eq!(grid.set(Pos::new(2, 2), 2), Some(1));
+        assert_eq!(grid.positions().collect::<Vec<_>>(), [Pos::new(2, 0)]);
+
+        // * Neighbours are wrapped onto the other side of the grid.
+        grid.set(Pos::new(0, 1), 3);
+        assert_eq!(
+            grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            [
+                (Pos::new(0, 1), &3),
+                (Pos::new(2, 0), &2),
+                (Pos::new(0, 1), &3)
+            ]
+        );
+        assert_eq!(grid.to_string(), "..2\n3..");
+    }
+}
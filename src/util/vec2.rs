@@ -0,0 +1,285 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::num::{Num, Signed};
+
+/// A position (or offset) on a 2D map, where `y` increases down the page (i.e. the same way as the lines of an input).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        return Self { x, y };
+    }
+}
+
+impl<T: Num> Vec2<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+
+    /// The sum of the distances along each axis, i.e. the number of orthogonal steps between the positions.
+    pub fn manhattan(self, other: Self) -> T {
+        return self.x.abs_diff(other.x) + self.y.abs_diff(other.y);
+    }
+
+    /// The largest of the distances along each axis, i.e. the number of orthogonal or diagonal steps between the positions.
+    pub fn chebyshev(self, other: Self) -> T {
+        return Ord::max(self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+    }
+
+    /// Whether this position is within the rectangle from `min` to `max` (inclusive).
+    pub fn in_bounds(self, min: Self, max: Self) -> bool {
+        return min.x <= self.x && self.x <= max.x && min.y <= self.y && self.y <= max.y;
+    }
+
+    /// Wrap this position around the rectangle from zero to `size` (exclusive), as if it were a torus.
+    pub fn rem_euclid(self, size: Self) -> Self {
+        return Self::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y));
+    }
+
+    /// Convert each coordinate into another type, e.g. `Vec2<usize>` into `Vec2<isize>`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vec2<U>> {
+        return Some(Vec2::new(self.x.try_into().ok()?, self.y.try_into().ok()?));
+    }
+}
+
+impl<T: Signed> Vec2<T> {
+    /// Rotate this offset 90° clockwise (as it would appear on the page), e.g. from `Dir::NY` to `Dir::PX`.
+    pub fn rotate_cw(self) -> Self {
+        return Self::new(-self.y, self.x);
+    }
+
+    /// Rotate this offset 90° anticlockwise (as it would appear on the page), e.g. from `Dir::PX` to `Dir::NY`.
+    pub fn rotate_ccw(self) -> Self {
+        return Self::new(self.y, -self.x);
+    }
+
+    /// The 4 orthogonally adjacent positions, in the same order as `Dir::ALL`.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        return Dir::ALL.into_iter().map(move |dir| self + dir);
+    }
+
+    /// The 8 orthogonally and diagonally adjacent positions.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        let (o, z) = (T::ONE, T::ZERO);
+        let offsets = [
+            (o, z),
+            (o, o),
+            (z, o),
+            (-o, o),
+            (-o, z),
+            (-o, -o),
+            (z, -o),
+            (o, -o),
+        ];
+        return offsets
+            .into_iter()
+            .map(move |(x, y)| self + Self::new(x, y));
+    }
+}
+
+impl<T: Num> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        return Self::new(self.x + rhs.x, self.y + rhs.y);
+    }
+}
+
+impl<T: Num> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Self::new(self.x - rhs.x, self.y - rhs.y);
+    }
+}
+
+impl<T: Num> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        return Self::new(self.x * rhs, self.y * rhs);
+    }
+}
+
+impl<T: Num> Div<T> for Vec2<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        return Self::new(self.x / rhs, self.y / rhs);
+    }
+}
+
+impl<T: Signed> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self::new(-self.x, -self.y);
+    }
+}
+
+impl<T: Num> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Num> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Num> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// One of the 4 orthogonal directions, where 'NY' is up the page (see `Vec2`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    PX,
+    PY,
+    NX,
+    NY,
+}
+
+impl Dir {
+    /// Every direction, in clockwise order starting from `PX`.
+    pub const ALL: [Self; 4] = [Self::PX, Self::PY, Self::NX, Self::NY];
+
+    /// Parse one of the arrows '>', 'v', '<' and '^'.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '>' => Some(Self::PX),
+            'v' => Some(Self::PY),
+            '<' => Some(Self::NX),
+            '^' => Some(Self::NY),
+            _ => None,
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        // *   NY
+        // * NX  PX
+        // *   PY
+        match self {
+            Self::PX => Self::PY,
+            Self::PY => Self::NX,
+            Self::NX => Self::NY,
+            Self::NY => Self::PX,
+        }
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        return self.rotate_cw().opposite();
+    }
+
+    pub fn opposite(self) -> Self {
+        return self.rotate_cw().rotate_cw();
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        return matches!(self, Self::PX | Self::NX);
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn to_vec<T: Signed>(self) -> Vec2<T> {
+        let (o, z) = (T::ONE, T::ZERO);
+        match self {
+            Self::PX => Vec2::new(o, z),
+            Self::PY => Vec2::new(z, o),
+            Self::NX => Vec2::new(-o, z),
+            Self::NY => Vec2::new(z, -o),
+        }
+    }
+}
+
+impl<T: Signed> Add<Dir> for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Dir) -> Self::Output {
+        return self + rhs.to_vec();
+    }
+}
+
+impl<T: Signed> AddAssign<Dir> for Vec2<T> {
+    fn add_assign(&mut self, rhs: Dir) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Signed> Sub<Dir> for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Dir) -> Self::Output {
+        return self - rhs.to_vec();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Vec2::new(3, -2), Vec2::new(-1, 5));
+        assert_eq!(a + b, Vec2::new(2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(a * 2, Vec2::new(6, -4));
+        assert_eq!(-a, Vec2::new(-3, 2));
+        assert_eq!(a + Dir::NY, Vec2::new(3, -3));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(Vec2::new(5u64, 1).manhattan(Vec2::new(2, 4)), 6);
+        assert_eq!(
+            Vec2::new(-1, 12).rem_euclid(Vec2::new(11, 7)),
+            Vec2::new(10, 5)
+        );
+        assert!(a.in_bounds(Vec2::new(0, -2), Vec2::new(3, 0)));
+        assert!(!b.in_bounds(Vec2::ZERO, Vec2::new(5, 5)));
+        assert_eq!(
+            Vec2::new(1usize, 2).try_cast::<i32>(),
+            Some(Vec2::new(1, 2))
+        );
+        assert_eq!(Vec2::new(-1, 2).try_cast::<usize>(), None);
+    }
+
+    #[test]
+    fn directions() {
+        for dir in Dir::ALL {
+            let v = dir.to_vec::<i32>();
+            assert_eq!(v.rotate_cw(), dir.rotate_cw().to_vec());
+            assert_eq!(v.rotate_ccw(), dir.rotate_ccw().to_vec());
+            assert_eq!(-v, dir.opposite().to_vec());
+        }
+        assert_eq!(Dir::from_arrow('^'), Some(Dir::NY));
+        assert_eq!(Dir::NY.rotate_cw(), Dir::PX);
+        assert!(Dir::NX.is_horizontal() && !Dir::PY.is_horizontal());
+
+        let pos = Vec2::new(0, 0);
+        assert_eq!(
+            pos.neighbours4().collect::<Vec<_>>(),
+            [
+                Vec2::new(1, 0),
+                Vec2::new(0, 1),
+                Vec2::new(-1, 0),
+                Vec2::new(0, -1)
+            ]
+        );
+        let neighbours = pos.neighbours8().collect::<Vec<_>>();
+        assert_eq!(neighbours.len(), 8);
+        assert!(neighbours.iter().all(|&n| n.chebyshev(pos) == 1));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Index, Mul, Neg, Sub, SubAssign},
+};
+
+use super::num::{Num, Signed};
+
+/// A position (or offset) in 3D space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        return Self { x, y, z };
+    }
+}
+
+impl<T: Num> Vec3<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    /// The sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T {
+        return self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z);
+    }
+
+    /// The square of the (Euclidean) distance between the positions, which sorts the same way as the distance itself but stays an integer.
+    pub fn dist_squared(self, other: Self) -> T {
+        let (dx, dy, dz) = (
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        );
+        return (dx * dx) + (dy * dy) + (dz * dz);
+    }
+}
+
+/// The coordinate along an axis, where 0, 1 and 2 are `x`, `y` and `z`.
+impl<T> Index<usize> for Vec3<T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Invalid axis {}!", axis),
+        }
+    }
+}
+
+impl<T: Num> Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        return Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z);
+    }
+}
+
+impl<T: Num> Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z);
+    }
+}
+
+impl<T: Num> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        return Self::new(self.x * rhs, self.y * rhs, self.z * rhs);
+    }
+}
+
+impl<T: Signed> Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self::new(-self.x, -self.y, -self.z);
+    }
+}
+
+impl<T: Num> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Num> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let (a, b) = (Vec3::new(1, -2, 3), Vec3::new(4, 0, -1));
+        assert_eq!(a + b, Vec3::new(5, -2, 2));
+        assert_eq!(a - b, Vec3::new(-3, -2, 4));
+        assert_eq!(-a * 2, Vec3::new(-2, 4, -6));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.dist_squared(b), 29);
+        assert_eq!(Vec3::new(5u64, 1, 2).dist_squared(Vec3::new(2, 1, 6)), 25);
+        assert_eq!((a[0], a[1], a[2]), (1, -2, 3));
+        assert_eq!(b.to_string(), "4,0,-1");
+    }
+}
@@ -0,0 +1,185 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use advent_of_code::{
+    bench,
+    input::{self, InputSource},
+    registry,
+    runner::{self, Outcome, Selection},
+};
+
+use crate::scaffold;
+
+/// How often watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A file that is checked for changes by polling its modification time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Watched {
+    pub path: PathBuf,
+    /// The modification time when the file was last checked, or `None` if it didn't exist.
+    modified: Option<SystemTime>,
+}
+
+impl Watched {
+    pub fn new(path: PathBuf) -> Self {
+        let modified = modified(&path);
+        return Self { path, modified };
+    }
+
+    /// Whether the file has been modified (or created or deleted) since it was last checked.
+    pub fn changed(&mut self) -> bool {
+        let modified = modified(&self.path);
+        if modified == self.modified {
+            return false;
+        }
+        self.modified = modified;
+        return true;
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    return fs::metadata(path).and_then(|m| m.modified()).ok();
+}
+
+/// Describe how `outcome` differs from the `previous` outcome of the same part, if there was one.
+pub fn diff(previous: Option<&Outcome>, outcome: &Outcome) -> String {
+    return match previous {
+        None => String::new(),
+        Some(previous) if previous == outcome => " (unchanged)".to_string(),
+        Some(previous) => format!(" (was {})", previous),
+    };
+}
+
+/// Run the selected day whenever its input (or `example`) changes, printing how each part's outcome changed.
+/// Changes to the day's source are only reported, since running them requires rebuilding.
+pub fn run(
+    selection: &Selection,
+    example: Option<PathBuf>,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let (year, day) = (selection.year, *selection.days.start());
+    let solution =
+        registry::find(year, day).ok_or_else(|| format!("No solution found for {}!", selection))?;
+    let parts = runner::selected_parts(solution, Some(selection));
+    // * Every day has a solver for part 1, so nothing is left to watch only if an unimplemented part 2 was selected.
+    if let (true, Some(part)) = (parts.is_empty(), selection.part) {
+        return Err(format!(
+            "{} day {}, part {} isn't implemented!",
+            year, day, part
+        ));
+    }
+
+    let mut inputs = vec![("input", Watched::new(input::default_path(year, day)))];
+    if let Some(example) = example {
+        inputs.push(("example", Watched::new(example)));
+    }
+    let source_path = Path::new(scaffold::SRC_DIR)
+        .join(format!("y{}", year))
+        .join(format!("d{}.rs", day));
+    let mut source = Watched::new(source_path);
+    // * The previous outcome of each part on each input, which new outcomes are compared against.
+    let mut previous = HashMap::new();
+
+    println!("Watching {} (press Ctrl+C to stop)...", selection);
+    let mut first = true;
+    loop {
+        if source.changed() {
+            println!(
+                "\n'{}' changed, rebuild to run the new code.",
+                source.path.display()
+            );
+        }
+        for (name, file) in inputs.iter_mut() {
+            if !file.changed() && !first {
+                continue;
+            }
+            println!("\n{} ('{}'):", name, file.path.display());
+            let source = InputSource::Path(file.path.clone());
+            let input: Arc<str> = match input::load(solution, &source) {
+                Ok(input) => input.into(),
+                Err(err) => {
+                    println!("  {}", err);
+                    continue;
+                }
+            };
+            for &part in &parts {
+                let solver = solution.solver(part).unwrap();
+                let start = Instant::now();
+                let outcome = runner::execute_with_timeout(solver, &input, timeout);
+                let duration = start.elapsed();
+                println!(
+                    "  part {}: {} ({}){}",
+                    part,
+                    outcome,
+                    bench::fmt_duration(duration),
+                    diff(previous.get(&(*name, part)), &outcome)
+                );
+                previous.insert((*name, part), outcome);
+            }
+        }
+        first = false;
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::{answer::Answer, solution::Part};
+
+    #[test]
+    fn changes() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut watched = Watched::new(path.clone());
+        assert_eq!(watched.modified, None);
+        assert!(!watched.changed());
+
+        fs::write(&path, "1").unwrap();
+        assert!(watched.changed());
+        assert!(!watched.changed());
+
+        // * Set the modification time explicitly, since it may be too coarse to tell two quick writes apart.
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+        assert!(watched.changed());
+        assert!(watched.modified.is_some());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watched.changed());
+        assert_eq!(watched.modified, None);
+    }
+
+    #[cfg(feature = "y2024")]
+    #[test]
+    fn unimplemented_part() {
+        let selection = Selection {
+            year: 2024,
+            days: 17..=17,
+            part: Some(Part::Two),
+        };
+        assert_eq!(
+            run(&selection, None, None),
+            Err("2024 day 17, part 2 isn't implemented!".to_string())
+        );
+    }
+
+    #[test]
+    fn diffs() {
+        let (one, two) = (
+            Outcome::Solved(Answer::Number(1)),
+            Outcome::Solved(Answer::Number(2)),
+        );
+        assert_eq!(diff(None, &one), "");
+        assert_eq!(diff(Some(&one), &one), " (unchanged)");
+        assert_eq!(diff(Some(&one), &two), " (was 1)");
+        assert_eq!(diff(Some(&Outcome::Todo), &two), " (was TODO)");
+    }
+}
@@ -1,7 +1,3 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2023", "1");
-
 enum LineSearch {
     None,
     OneChar(char),
@@ -26,9 +22,9 @@ impl LineSearch {
     }
 }
 
-pub fn solve_pt1() -> u32 {
+pub fn solve_pt1(input: &str) -> u32 {
     let mut res = 0;
-    for line in INPUT.lines() {
+    for line in input.lines() {
         let mut search = LineSearch::None;
         // * Iterate through characters on each line.
         for c in line.chars() {
@@ -41,7 +37,7 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
+pub fn solve_pt2(input: &str) -> u32 {
     const SPELLED_LOOKUP: [(&str, char); 9] = [
         ("one", '1'),
         ("two", '2'),
@@ -55,7 +51,7 @@ pub fn solve_pt2() -> u32 {
     ];
 
     let mut res = 0;
-    for line in INPUT.lines() {
+    for line in input.lines() {
         let mut search = LineSearch::None;
         let mut spelled = String::new();
 
@@ -83,3 +79,33 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PT1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    const EXAMPLE_PT2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE_PT1), 142);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE_PT2), 281);
+    }
+}
@@ -1,7 +1,3 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2023", "2");
-
 struct Game {
     pub id: u32,
     pub groups: Vec<CountGroup>,
@@ -30,9 +26,9 @@ impl CountGroup {
     }
 }
 
-fn load_games() -> Vec<Game> {
+fn load_games(input: &str) -> Vec<Game> {
     let mut res = Vec::new();
-    for line in INPUT.lines() {
+    for line in input.lines() {
         // * Skip the 'Game ' prefix of every line.
         let mut chars = line.chars().skip(5);
 
@@ -95,14 +91,14 @@ fn load_games() -> Vec<Game> {
     return res;
 }
 
-pub fn solve_pt1() -> u32 {
+pub fn solve_pt1(input: &str) -> u32 {
     const REQUIRED_RED: u32 = 12;
     const REQUIRED_GREEN: u32 = 13;
     const REQUIRED_BLUE: u32 = 14;
 
     let mut res = 0;
 
-    let games = load_games();
+    let games = load_games(input);
     for game in games {
         // * Find the maximum counts for each color from this game's groups.
         let mut max_counts = CountGroup::new();
@@ -124,10 +120,10 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
+pub fn solve_pt2(input: &str) -> u32 {
     let mut res = 0;
 
-    let games = load_games();
+    let games = load_games(input);
     for game in games {
         // * Find the maximum counts for each color from this game's groups.
         let mut max_counts = CountGroup::new();
@@ -143,3 +139,25 @@ pub fn solve_pt2() -> u32 {
 
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 8);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 2286);
+    }
+}
@@ -1,8 +1,5 @@
-use crate::include_input;
 use std::{collections::HashMap, ops::RangeInclusive};
 
-const INPUT: &str = include_input!("2023", "3");
-
 struct LineNumber {
     pub num: u32,
     pub line_idx: usize,
@@ -43,14 +40,14 @@ impl LineRange {
     }
 }
 
-pub fn solve_pt1() -> u32 {
+pub fn solve_pt1(input: &str) -> u32 {
     // * `numbers` consists of each number and the indices of its characters in the schematic.
     let mut numbers = Vec::new();
     // * `symbols` is used to determine if a specific position on the schematic is a 'symbol'.
     let mut symbols = Vec::new();
     let mut max_line_idx = 0;
 
-    for (line_idx, line) in INPUT.lines().enumerate() {
+    for (line_idx, line) in input.lines().enumerate() {
         max_line_idx = line_idx;
 
         let mut line_symbols = Vec::new();
@@ -114,12 +111,12 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
+pub fn solve_pt2(input: &str) -> u32 {
     let mut numbers = Vec::new();
     let mut gears = Vec::new();
     let mut max_line_idx = 0;
 
-    for (line_idx, line) in INPUT.lines().enumerate() {
+    for (line_idx, line) in input.lines().enumerate() {
         max_line_idx = line_idx;
 
         let mut line_gears = Vec::new();
@@ -192,3 +189,30 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 4361);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 467835);
+    }
+}
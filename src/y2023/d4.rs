@@ -1,8 +1,5 @@
-use crate::include_input;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_input!("2023", "4");
-
 #[derive(Debug)]
 struct Card {
     pub id: u32,
@@ -12,10 +9,10 @@ struct Card {
 
 impl Card {}
 
-fn load_cards() -> Vec<Card> {
+fn load_cards(input: &str) -> Vec<Card> {
     let mut res = Vec::new();
 
-    for line in INPUT.lines() {
+    for line in input.lines() {
         // * Skip the 'Card ' prefix of every line.
         let string = line.to_string();
         // * `.skip(1)` to skip the 'Card' prefix of each line.
@@ -55,8 +52,8 @@ fn load_cards() -> Vec<Card> {
     return res;
 }
 
-pub fn solve_pt1() -> u32 {
-    let cards = load_cards();
+pub fn solve_pt1(input: &str) -> u32 {
+    let cards = load_cards(input);
     let mut res = 0;
 
     for card in cards {
@@ -75,9 +72,9 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
+pub fn solve_pt2(input: &str) -> u32 {
     // TODO: This is kinda slow.
-    let cards = load_cards();
+    let cards = load_cards(input);
 
     let mut res = 0;
     let mut max_id = 0;
@@ -107,3 +104,26 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 13);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 30);
+    }
+}
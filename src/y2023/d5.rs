@@ -1,7 +1,3 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2023", "5");
-
 #[derive(Debug)]
 struct Category {
     pub _name: String,
@@ -44,13 +40,13 @@ impl Map {
     }
 }
 
-fn load_inputs() -> (Vec<u64>, Vec<Category>) {
+fn load_inputs(input: &str) -> (Vec<u64>, Vec<Category>) {
     // * Initial seed inputs.
     let mut res_inputs = Vec::new();
     // * Maps between each category.
     let mut res_maps = Vec::new();
 
-    let mut lines = INPUT.lines();
+    let mut lines = input.lines();
     let mut inputs_chars = lines.next().unwrap().chars().skip(7);
     loop {
         let input_str = inputs_chars
@@ -92,8 +88,8 @@ fn load_inputs() -> (Vec<u64>, Vec<Category>) {
     return (res_inputs, res_maps);
 }
 
-pub fn solve_pt1() -> u64 {
-    let (inputs, categories) = load_inputs();
+pub fn solve_pt1(input: &str) -> u64 {
+    let (inputs, categories) = load_inputs(input);
 
     let mut res = u64::MAX;
     for mut input in inputs {
@@ -125,9 +121,9 @@ impl Interval {
     }
 }
 
-pub fn solve_pt2() -> u64 {
+pub fn solve_pt2(input: &str) -> u64 {
     // ? Thanks to https://www.youtube.com/watch?v=EGQgUYx-2gE for explaining the non-bruteforce 'interval' method.
-    let (inputs, categories) = load_inputs();
+    let (inputs, categories) = load_inputs(input);
     let mut res = u64::MAX;
 
     let mut stack = Vec::new();
@@ -193,3 +189,53 @@ pub fn solve_pt2() -> u64 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 35);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 46);
+    }
+}
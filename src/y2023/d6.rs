@@ -1,10 +1,7 @@
-use crate::include_input;
 use std::iter;
 
-const INPUT: &str = include_input!("2023", "6");
-
-pub fn solve_pt1() -> u64 {
-    let lines = INPUT.lines().collect::<Vec<_>>();
+pub fn solve_pt1(input: &str) -> u64 {
+    let lines = input.lines().collect::<Vec<_>>();
     let times = lines[0]
         .split_whitespace()
         .skip(1)
@@ -30,10 +27,10 @@ pub fn solve_pt1() -> u64 {
     return res;
 }
 
-pub fn solve_pt2() -> u64 {
+pub fn solve_pt2(input: &str) -> u64 {
     // TODO: This is kinda slow; probably from the `.collect::<String>()` call.
 
-    let lines = INPUT.lines().collect::<Vec<_>>();
+    let lines = input.lines().collect::<Vec<_>>();
     let time = lines[0]
         .split_whitespace()
         .skip(1)
@@ -56,3 +53,22 @@ pub fn solve_pt2() -> u64 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 288);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 71503);
+    }
+}
@@ -1,6 +1,18 @@
+use crate::{day, solution::Day};
+
 pub mod d1;
 pub mod d2;
 pub mod d3;
 pub mod d4;
 pub mod d5;
 pub mod d6;
+
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+    day!(2023, 1, "Trebuchet?!", d1::solve_pt1, d1::solve_pt2),
+    day!(2023, 2, "Cube Conundrum", d2::solve_pt1, d2::solve_pt2),
+    day!(2023, 3, "Gear Ratios", d3::solve_pt1, d3::solve_pt2),
+    day!(2023, 4, "Scratchcards", d4::solve_pt1, d4::solve_pt2),
+    day!(2023, 5, "If You Give A Seed A Fertilizer", d5::solve_pt1, d5::solve_pt2),
+    day!(2023, 6, "Wait For It", d6::solve_pt1, d6::solve_pt2),
+];
@@ -1,20 +1,18 @@
-use crate::include_input;
 use std::{
     cmp::{max, min},
     collections::HashMap,
     iter::zip,
 };
 
-const INPUT: &str = include_input!("2024", "1");
-
-fn load_lists() -> (Vec<u32>, Vec<u32>) {
+pub(super) fn load_lists(input: &str) -> (Vec<u32>, Vec<u32>) {
     // * Read lists into two Vec<u32>.
     let mut list1 = Vec::new();
     let mut list2 = Vec::new();
 
-    for line in INPUT.lines() {
-        let num1 = line[0..5].parse::<u32>().unwrap();
-        let num2 = line[8..13].parse::<u32>().unwrap();
+    for line in input.lines() {
+        let mut nums = line.split_whitespace().map(|n| n.parse::<u32>().unwrap());
+        let num1 = nums.next().unwrap();
+        let num2 = nums.next().unwrap();
         list1.push(num1);
         list2.push(num2);
     }
@@ -22,8 +20,8 @@ fn load_lists() -> (Vec<u32>, Vec<u32>) {
     return (list1, list2);
 }
 
-pub fn solve_pt1() -> u32 {
-    let (mut list1, mut list2) = load_lists();
+pub fn solve_pt1(input: &str) -> u32 {
+    let (mut list1, mut list2) = load_lists(input);
 
     // * Sort the two lists in ascending order.
     list1.sort_unstable();
@@ -37,8 +35,8 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
-    let (list1, list2) = load_lists();
+pub fn solve_pt2(input: &str) -> u32 {
+    let (list1, list2) = load_lists(input);
 
     // * Get counts of each number in list2.
     let mut counts = HashMap::new();
@@ -58,3 +56,26 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 11);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 31);
+    }
+}
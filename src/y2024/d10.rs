@@ -1,59 +1,16 @@
-use crate::include_input;
-use std::{
-    collections::{HashMap, HashSet},
-    ops::Add,
-};
+use std::collections::HashSet;
 
-const INPUT: &str = include_input!("2024", "10");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    pub const PX: Self = Self::new(1, 0);
-    pub const PY: Self = Self::new(0, 1);
-    pub const NX: Self = Self::new(-1, 0);
-    pub const NY: Self = Self::new(0, -1);
-
-    pub const ALL_DIRS: [Self; 4] = [Self::PX, Self::PY, Self::NX, Self::NY];
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
+use crate::util::grid::{Grid, Pos};
 
 struct Map {
-    pub heights: HashMap<Pos, u32>,
+    pub heights: Grid<u32>,
     pub trailheads: HashSet<Pos>,
 }
 
 impl Map {
-    pub fn load() -> Self {
-        let mut heights = HashMap::new();
-        let mut trailheads = HashSet::new();
-
-        for (y, line) in INPUT.lines().enumerate() {
-            let y = y as isize;
-            for (x, c) in line.char_indices() {
-                let pos = Pos::new(x as isize, y);
-                let height = c.to_digit(10).unwrap();
-                heights.insert(pos, height);
-                if height == 0 {
-                    trailheads.insert(pos);
-                }
-            }
-        }
+    pub fn load(input: &str) -> Self {
+        let heights = Grid::parse(input, |c| c.to_digit(10).unwrap());
+        let trailheads = heights.find_all(|&h| h == 0).collect();
         return Self {
             heights,
             trailheads,
@@ -61,50 +18,69 @@ impl Map {
     }
 }
 
-pub fn solve_pt1() -> u32 {
-    let map = Map::load();
+pub fn solve_pt1(input: &str) -> u32 {
+    let map = Map::load(input);
     let mut buffer = Vec::from_iter(map.trailheads.into_iter().enumerate());
     // * Each trail of a trailhead must have both a unique ending position for pt. 1.
     let mut completed_trails = HashSet::new();
 
     while let Some((trail_id, pos)) = buffer.pop() {
-        let current_height = map.heights[&pos];
+        let current_height = map.heights[pos];
         if current_height == 9 {
             completed_trails.insert((trail_id, pos));
             continue;
         }
-        for dir in Pos::ALL_DIRS {
-            let next = pos + dir;
-            if let Some(next_height) = map.heights.get(&next) {
-                if *next_height == current_height + 1 {
-                    buffer.push((trail_id, next));
-                }
+        for (next, &next_height) in map.heights.neighbours4(pos) {
+            if next_height == current_height + 1 {
+                buffer.push((trail_id, next));
             }
         }
     }
     return completed_trails.len() as u32;
 }
 
-pub fn solve_pt2() -> u32 {
-    let map = Map::load();
+pub fn solve_pt2(input: &str) -> u32 {
+    let map = Map::load(input);
     let mut buffer = Vec::from_iter(map.trailheads.into_iter());
     // * Pt. 2 no longer requires unique ending positions for each trail of a trailhead.
     let mut res = 0;
 
     while let Some(pos) = buffer.pop() {
-        let current_height = map.heights[&pos];
+        let current_height = map.heights[pos];
         if current_height == 9 {
             res += 1;
             continue;
         }
-        for dir in Pos::ALL_DIRS {
-            let next = pos + dir;
-            if let Some(next_height) = map.heights.get(&next) {
-                if *next_height == current_height + 1 {
-                    buffer.push(next);
-                }
+        for (next, &next_height) in map.heights.neighbours4(pos) {
+            if next_height == current_height + 1 {
+                buffer.push(next);
             }
         }
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 36);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 81);
+    }
+}
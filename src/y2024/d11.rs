@@ -1,9 +1,5 @@
 use std::collections::HashMap;
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "11");
-
 /// Add `count` to the value of `map[key]`, or insert it if not found.
 fn add_or_insert(map: &mut HashMap<u64, u64>, key: u64, count: u64) {
     match map.get_mut(&key) {
@@ -16,13 +12,13 @@ fn add_or_insert(map: &mut HashMap<u64, u64>, key: u64, count: u64) {
     }
 }
 
-fn load_stones() -> HashMap<u64, u64> {
-    return INPUT.split(' ').map(|n| (n.parse().unwrap(), 1)).collect();
+pub(super) fn load_stones(input: &str) -> HashMap<u64, u64> {
+    return input.split(' ').map(|n| (n.parse().unwrap(), 1)).collect();
 }
 
-pub fn solve_pt1() -> u64 {
+pub fn solve_pt1(input: &str) -> u64 {
     const NUM_BLINKS: u64 = 25;
-    let mut stones = load_stones();
+    let mut stones = load_stones(input);
     let mut next = HashMap::new();
 
     for _ in 0..NUM_BLINKS {
@@ -51,9 +47,9 @@ pub fn solve_pt1() -> u64 {
     return stones.into_values().sum();
 }
 
-pub fn solve_pt2() -> u64 {
+pub fn solve_pt2(input: &str) -> u64 {
     const NUM_BLINKS: u64 = 75;
-    let mut stones = load_stones();
+    let mut stones = load_stones(input);
     let mut next = HashMap::new();
 
     for _ in 0..NUM_BLINKS {
@@ -81,3 +77,21 @@ pub fn solve_pt2() -> u64 {
     }
     return stones.into_values().sum();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+125 17";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 55312);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 65601038650482);
+    }
+}
@@ -1,118 +1,71 @@
-use crate::include_input;
-use std::{
-    collections::{HashMap, HashSet},
-    ops::{Add, Sub},
-};
-
-const INPUT: &str = include_input!("2024", "12");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    // * Rotates this position 90° clockwise.
-    pub fn rotate(&self) -> Self {
-        return Self::new(self.y, -self.x);
-    }
-
-    pub const PX: Self = Self::new(1, 0);
-    pub const PY: Self = Self::new(0, 1);
-    pub const NX: Self = Self::new(-1, 0);
-    pub const NY: Self = Self::new(0, -1);
-
-    pub const ALL_DIRS: [Self; 4] = [Self::PX, Self::PY, Self::NX, Self::NY];
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
+use std::collections::HashSet;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
+use crate::util::grid::Grid;
 
-fn load_plants() -> HashMap<Pos, char> {
-    let mut plants = HashMap::new();
-    for (y, line) in INPUT.lines().enumerate() {
-        for (x, c) in line.char_indices() {
-            let pos = Pos::new(x as isize, y as isize);
-            plants.insert(pos, c);
-        }
-    }
-    return plants;
-}
-
-pub fn solve_pt1() -> u32 {
-    let mut plants = load_plants();
+pub fn solve_pt1(input: &str) -> u32 {
+    let plants = Grid::parse(input, |c| c);
+    // * Whether each plant has already been counted as part of a region.
+    let mut counted = plants.map(|_| false);
     let mut res = 0;
     let mut stack = Vec::new();
     let mut region = HashSet::new();
 
-    while let Some((&start_pos, &region_plant)) = plants.iter().next() {
+    for (start_pos, &region_plant) in plants.iter() {
+        if counted[start_pos] {
+            continue;
+        }
         let mut perimeter = 0;
         region.clear();
         stack.push(start_pos);
 
         while let Some(pos) = stack.pop() {
             if region.insert(pos) {
-                for dir in Pos::ALL_DIRS {
-                    let next = pos + dir;
-                    if plants.get(&next).is_none_or(|&c| c != region_plant) {
+                for next in pos.neighbours4() {
+                    if plants.get(next).is_none_or(|&c| c != region_plant) {
                         // * `next` is not within the current region.
                         perimeter += 1;
                     } else {
                         // * `next` is within the current region.
-                        stack.push(pos + dir);
+                        stack.push(next);
                     }
                 }
             }
         }
         res += region.len() * perimeter;
 
-        for p in &region {
-            plants.remove(p);
+        for &p in &region {
+            counted[p] = true;
         }
     }
     return res as u32;
 }
 
-pub fn solve_pt2() -> u32 {
-    let mut plants = load_plants();
+pub fn solve_pt2(input: &str) -> u32 {
+    let plants = Grid::parse(input, |c| c);
+    // * Whether each plant has already been counted as part of a region.
+    let mut counted = plants.map(|_| false);
     let mut res = 0;
     let mut stack = Vec::new();
     let mut region = HashSet::new();
     let mut perimeter = HashSet::new();
 
-    while let Some((&start_pos, &region_plant)) = plants.iter().next() {
+    for (start_pos, &region_plant) in plants.iter() {
+        if counted[start_pos] {
+            continue;
+        }
         perimeter.clear();
         region.clear();
         stack.push(start_pos);
 
         while let Some(pos) = stack.pop() {
             if region.insert(pos) {
-                for dir in Pos::ALL_DIRS {
-                    let next = pos + dir;
-                    if plants.get(&next).is_none_or(|&c| c != region_plant) {
+                for next in pos.neighbours4() {
+                    if plants.get(next).is_none_or(|&c| c != region_plant) {
                         // * `next` is not within the current region.
                         perimeter.insert((pos, next));
                     } else {
                         // * `next` is within the current region.
-                        stack.push(pos + dir);
+                        stack.push(next);
                     }
                 }
             }
@@ -131,7 +84,7 @@ pub fn solve_pt2() -> u32 {
 
             let (inside, outside) = pair;
             let inside_dir = inside - outside;
-            let move_dir = inside_dir.rotate();
+            let move_dir = inside_dir.rotate_cw();
             let mut current_pos = outside;
             loop {
                 let inside_pos = current_pos + inside_dir;
@@ -145,15 +98,42 @@ pub fn solve_pt2() -> u32 {
                     sides += 1;
                     break;
                 }
-                current_pos = current_pos + move_dir;
+                current_pos += move_dir;
             }
         }
 
         res += region.len() * sides;
 
-        for p in &region {
-            plants.remove(p);
+        for &p in &region {
+            counted[p] = true;
         }
     }
     return res as u32;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 1930);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 1206);
+    }
+}
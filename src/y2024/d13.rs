@@ -1,46 +1,9 @@
-use crate::include_input;
-use std::ops::{Add, Mul, Sub};
-
-const INPUT: &str = include_input!("2024", "13");
+use crate::util::vec2::Vec2;
 
 const COST_A: u64 = 3;
 const COST_B: u64 = 1;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Pos {
-    pub x: u64,
-    pub y: u64,
-}
-
-impl Pos {
-    pub const fn new(x: u64, y: u64) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl Mul<Pos> for u64 {
-    type Output = Pos;
-
-    fn mul(self, rhs: Pos) -> Self::Output {
-        return Pos::new(self * rhs.x, self * rhs.y);
-    }
-}
+type Pos = Vec2<u64>;
 
 #[derive(Debug)]
 struct Machine {
@@ -58,9 +21,9 @@ impl Machine {
             .unwrap();
     }
 
-    pub fn load() -> Vec<Self> {
+    pub fn load(input: &str) -> Vec<Self> {
         let mut res = Vec::new();
-        for m in INPUT.split("\n\n") {
+        for m in input.split("\n\n") {
             let mut chars = m.chars();
             // * Skip "Button A: X+", read `ax`.
             let mut chars = chars.by_ref().skip(12);
@@ -91,8 +54,8 @@ impl Machine {
     }
 }
 
-pub fn solve_pt1() -> u64 {
-    let machines = Machine::load();
+pub fn solve_pt1(input: &str) -> u64 {
+    let machines = Machine::load(input);
     let mut res = 0;
 
     for machine in machines {
@@ -118,8 +81,8 @@ pub fn solve_pt1() -> u64 {
     return res as u64;
 }
 
-pub fn solve_pt2() -> u64 {
-    let machines = Machine::load();
+pub fn solve_pt2(input: &str) -> u64 {
+    let machines = Machine::load(input);
     let mut res = 0;
 
     for machine in machines {
@@ -139,3 +102,35 @@ pub fn solve_pt2() -> u64 {
     }
     return res as u64;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 480);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 875318608908);
+    }
+}
@@ -1,54 +1,6 @@
-use crate::include_input;
-use std::{
-    cmp::Ordering,
-    collections::HashSet,
-    ops::{Add, Mul, Sub},
-};
-
-const INPUT: &str = include_input!("2024", "14");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    pub const PX: Self = Self::new(1, 0);
-    pub const PY: Self = Self::new(0, 1);
-    pub const NX: Self = Self::new(-1, 0);
-    pub const NY: Self = Self::new(0, -1);
-
-    pub const ALL_DIRS: [Self; 4] = [Self::PX, Self::PY, Self::NX, Self::NY];
-}
+use std::{cmp::Ordering, collections::HashSet};
 
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl Mul<Pos> for isize {
-    type Output = Pos;
-
-    fn mul(self, rhs: Pos) -> Self::Output {
-        return Pos::new(self * rhs.x, self * rhs.y);
-    }
-}
+use crate::util::{grid::Pos, sparse_grid::SparseGrid};
 
 #[derive(Debug)]
 struct Robot {
@@ -65,9 +17,9 @@ impl Robot {
             .unwrap();
     }
 
-    pub fn load() -> Vec<Robot> {
+    pub fn load(input: &str) -> Vec<Robot> {
         let mut res = Vec::new();
-        for line in INPUT.lines() {
+        for line in input.lines() {
             let chars = line.chars();
             // * Skip "p=", read `pos`.
             let mut chars = chars.skip(2);
@@ -86,36 +38,31 @@ impl Robot {
     }
 }
 
-/// Rust's built-in modulus function does handle negatives correctly for this puzzle.
-#[inline]
-fn pos_mod(mut lhs: isize, rhs: isize) -> isize {
-    lhs %= rhs;
-    if lhs < 0 {
-        lhs += rhs;
-    }
-    return lhs;
+/// The size of the space that the robots move within (the example input instead uses 11 x 7).
+const BOUNDS: Pos = Pos::new(101, 103);
+
+pub fn solve_pt1(input: &str) -> u32 {
+    return safety_factor(input, BOUNDS);
 }
 
-pub fn solve_pt1() -> u32 {
+fn safety_factor(input: &str, bounds: Pos) -> u32 {
     const PERIOD: isize = 100;
-    const BOUNDS: Pos = Pos::new(101, 103);
-    const HALF: Pos = Pos::new(50, 51);
+    let half = bounds / 2;
 
     let mut count_1 = 0;
     let mut count_2 = 0;
     let mut count_3 = 0;
     let mut count_4 = 0;
 
-    let mut robots = Robot::load();
+    let mut robots = Robot::load(input);
     for robot in &mut robots {
-        let mut final_pos = robot.pos + (PERIOD * robot.vel);
-        final_pos.x = pos_mod(final_pos.x, BOUNDS.x);
-        final_pos.y = pos_mod(final_pos.y, BOUNDS.y);
+        // * Rust's built-in modulus function doesn't handle negatives correctly for this puzzle, hence `rem_euclid`.
+        let final_pos = (robot.pos + robot.vel * PERIOD).rem_euclid(bounds);
         robot.pos = final_pos;
 
         match (
-            Ord::cmp(&final_pos.x, &HALF.x),
-            Ord::cmp(&final_pos.y, &HALF.y),
+            Ord::cmp(&final_pos.x, &half.x),
+            Ord::cmp(&final_pos.y, &half.y),
         ) {
             // * `final_pos` is in one of the four quadrants.
             (Ordering::Less, Ordering::Less) => count_1 += 1,
@@ -132,39 +79,35 @@ pub fn solve_pt1() -> u32 {
 // ? FYI: This puzzle is really annoying, since the prompt provides no real way to determine if the robots are in the correct position other than having the user scan through steps.
 // ! For future me - DO NOT add this solution to any tests, as it relies on user input.
 #[allow(unreachable_code)]
-pub fn solve_pt2() -> u32 {
-    const BOUNDS: Pos = Pos::new(101, 103);
-
-    let mut robots = Robot::load();
-    let mut positions = HashSet::new();
+pub fn solve_pt2(input: &str) -> u32 {
+    let mut robots = Robot::load(input);
+    let mut positions = SparseGrid::new();
     let mut checked_positions = HashSet::new();
     for time in 1u32.. {
         positions.clear();
         checked_positions.clear();
 
         for robot in &mut robots {
-            let mut next_pos = robot.pos + robot.vel;
-            next_pos.x = pos_mod(next_pos.x, BOUNDS.x);
-            next_pos.y = pos_mod(next_pos.y, BOUNDS.y);
-            robot.pos = next_pos;
-            positions.insert(next_pos);
+            robot.pos = (robot.pos + robot.vel).rem_euclid(BOUNDS);
+            positions.set(robot.pos, ());
         }
 
         // * Search for 'blobs' (using flood fill) larger than some arbitary value to skip random states (This question *really* annoys me).
 
         let mut max_blob = 0;
         let mut stack = Vec::new();
-        while let Some(start_pos) = positions.iter().next().cloned() {
+        loop {
+            let Some(start_pos) = positions.positions().next() else {
+                break;
+            };
             stack.clear();
             stack.push(start_pos);
             let mut current_blob = 0;
             while let Some(pos) = stack.pop() {
-                if positions.remove(&pos) {
+                if positions.remove(pos).is_some() {
                     current_blob += 1;
                     checked_positions.insert(pos);
-                    for dir in Pos::ALL_DIRS {
-                        stack.push(pos + dir);
-                    }
+                    stack.extend(pos.neighbours4().filter(|&next| positions.contains(next)));
                 }
             }
             if current_blob > max_blob {
@@ -206,3 +149,27 @@ pub fn solve_pt2() -> u32 {
     }
     panic!("No correct state found after {} steps!", u32::MAX);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(safety_factor(EXAMPLE, Pos::new(11, 7)), 12);
+    }
+}
@@ -1,43 +1,29 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::{Add, Sub},
-};
+use std::collections::HashSet;
 
-use crate::include_input;
+use crate::util::{grid::Pos, sparse_grid::SparseGrid, vec2::Dir};
 
-const INPUT: &str = include_input!("2024", "15");
+/// Load the robot's position, the warehouse's walls and boxes (as their characters), and the robot's movements.
+pub(super) fn load_inputs(input: &str) -> (Pos, SparseGrid<char>, Vec<Dir>) {
+    let (input_warehouse, input_movements) = input.split_once("\n\n").unwrap();
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
+    let mut warehouse = SparseGrid::parse(input_warehouse, |c| match c {
+        '#' | 'O' | '@' => Some(c),
+        '.' => None,
+        _ => panic!("Invalid warehouse input!"),
+    });
+    let robot_pos = warehouse.find(|&c| c == '@').unwrap();
+    warehouse.remove(robot_pos);
 
-    pub const PX: Self = Self::new(1, 0);
-    pub const PY: Self = Self::new(0, 1);
-    pub const NX: Self = Self::new(-1, 0);
-    pub const NY: Self = Self::new(0, -1);
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
+    let mut movements = Vec::new();
+    for c in input_movements.chars() {
+        let dir = match c {
+            '\n' => continue,
+            c => Dir::from_arrow(c).expect("Invalid movement input!"),
+        };
+        movements.push(dir);
     }
-}
-
-impl Sub for Pos {
-    type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
+    return (robot_pos, warehouse, movements);
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -46,46 +32,17 @@ enum TilePt1 {
     Wall,
 }
 
-fn load_inputs_pt1() -> (Pos, HashMap<Pos, TilePt1>, Vec<Pos>) {
-    let (input_warehouse, input_movements) = INPUT.split_once("\n\n").unwrap();
-
-    let mut warehouse = HashMap::new();
-    let mut robot_pos = Pos::new(0, 0);
-    for (y, line) in input_warehouse.lines().enumerate() {
-        for (x, c) in line.char_indices() {
-            let pos = Pos::new(x as isize, y as isize);
-            let tile = match c {
-                'O' => TilePt1::Box,
-                '#' => TilePt1::Wall,
-                '@' => {
-                    robot_pos = pos;
-                    continue;
-                }
-                '.' => continue,
-                _ => panic!("Invalid warehouse input!"),
-            };
-            warehouse.insert(pos, tile);
-        }
-    }
-
-    let mut movements = Vec::new();
-    for c in input_movements.chars() {
-        let dir = match c {
-            '>' => Pos::PX,
-            'v' => Pos::PY,
-            '<' => Pos::NX,
-            '^' => Pos::NY,
-            '\n' => continue,
-            _ => panic!("Invalid movement input!"),
-        };
-        movements.push(dir);
-    }
-
+fn load_inputs_pt1(input: &str) -> (Pos, SparseGrid<TilePt1>, Vec<Dir>) {
+    let (robot_pos, warehouse, movements) = load_inputs(input);
+    let warehouse = warehouse.map(|&c| match c {
+        'O' => TilePt1::Box,
+        _ => TilePt1::Wall,
+    });
     return (robot_pos, warehouse, movements);
 }
 
-pub fn solve_pt1() -> u32 {
-    let (mut robot_pos, mut map, movements) = load_inputs_pt1();
+pub fn solve_pt1(input: &str) -> u32 {
+    let (mut robot_pos, mut map, movements) = load_inputs_pt1(input);
 
     let mut to_move = HashSet::new();
     for dir in movements {
@@ -93,8 +50,8 @@ pub fn solve_pt1() -> u32 {
         let mut current_pos = robot_pos;
         let mut can_move = false;
         loop {
-            current_pos = current_pos + dir;
-            match map.get(&current_pos) {
+            current_pos += dir;
+            match map.get(current_pos) {
                 Some(TilePt1::Box) => {
                     to_move.insert(current_pos);
                 }
@@ -108,18 +65,18 @@ pub fn solve_pt1() -> u32 {
             }
         }
         if can_move {
-            robot_pos = robot_pos + dir;
-            for box_pos in &to_move {
+            robot_pos += dir;
+            for &box_pos in &to_move {
                 map.remove(box_pos);
             }
             for &box_pos in &to_move {
-                map.insert(box_pos + dir, TilePt1::Box);
+                map.set(box_pos + dir, TilePt1::Box);
             }
         }
     }
 
     let mut res = 0;
-    for (pos, tile) in map {
+    for (pos, &tile) in map.iter() {
         if tile == TilePt1::Box {
             res += pos.x + (100 * pos.y);
         }
@@ -134,53 +91,29 @@ enum TilePt2 {
     BoxRight,
 }
 
-fn load_inputs_pt2() -> (Pos, HashMap<Pos, TilePt2>, Vec<Pos>) {
-    let (input_warehouse, input_movements) = INPUT.split_once("\n\n").unwrap();
+fn load_inputs_pt2(input: &str) -> (Pos, SparseGrid<TilePt2>, Vec<Dir>) {
+    let (robot_pos, warehouse_pt1, movements) = load_inputs(input);
 
-    let mut warehouse = HashMap::new();
-    let mut robot_pos = Pos::new(0, 0);
-    for (y, line) in input_warehouse.lines().enumerate() {
-        for (mut x, c) in line.char_indices() {
-            x *= 2;
-            let pos_left = Pos::new(x as isize, y as isize);
-            let pos_right = Pos::new((x + 1) as isize, y as isize);
-            match c {
-                '#' => {
-                    warehouse.insert(pos_left, TilePt2::Wall);
-                    warehouse.insert(pos_right, TilePt2::Wall);
-                }
-                'O' => {
-                    warehouse.insert(pos_left, TilePt2::BoxLeft);
-                    warehouse.insert(pos_right, TilePt2::BoxRight);
-                }
-                '@' => {
-                    robot_pos = pos_left;
-                    continue;
-                }
-                '.' => continue,
-                _ => panic!("Invalid warehouse input!"),
-            }
+    // * Everything except the robot is twice as wide in pt. 2.
+    let mut warehouse = SparseGrid::new();
+    for (pos, &c) in warehouse_pt1.iter() {
+        let pos_left = Pos::new(pos.x * 2, pos.y);
+        let pos_right = pos_left + Dir::PX;
+        if c == 'O' {
+            warehouse.set(pos_left, TilePt2::BoxLeft);
+            warehouse.set(pos_right, TilePt2::BoxRight);
+        } else {
+            warehouse.set(pos_left, TilePt2::Wall);
+            warehouse.set(pos_right, TilePt2::Wall);
         }
     }
-
-    let mut movements = Vec::new();
-    for c in input_movements.chars() {
-        let dir = match c {
-            '>' => Pos::PX,
-            'v' => Pos::PY,
-            '<' => Pos::NX,
-            '^' => Pos::NY,
-            '\n' => continue,
-            _ => panic!("Invalid movement input!"),
-        };
-        movements.push(dir);
-    }
+    let robot_pos = Pos::new(robot_pos.x * 2, robot_pos.y);
 
     return (robot_pos, warehouse, movements);
 }
 
-pub fn solve_pt2() -> u32 {
-    let (mut robot_pos, mut map, movements) = load_inputs_pt2();
+pub fn solve_pt2(input: &str) -> u32 {
+    let (mut robot_pos, mut map, movements) = load_inputs_pt2(input);
 
     // * In the case of part 2, we'll just store the left position of each box in `to_move`.
     let mut to_move = HashSet::new();
@@ -189,7 +122,7 @@ pub fn solve_pt2() -> u32 {
         to_move.clear();
         let mut next_pos = robot_pos + dir;
 
-        let left_pos = match map.get_mut(&next_pos) {
+        let left_pos = match map.get(next_pos) {
             None => {
                 // * The next tile is already empty, so it's safe to move without considering boxes.
                 robot_pos = next_pos;
@@ -200,14 +133,14 @@ pub fn solve_pt2() -> u32 {
                 continue;
             }
             Some(TilePt2::BoxLeft) => next_pos,
-            Some(TilePt2::BoxRight) => next_pos + Pos::NX,
+            Some(TilePt2::BoxRight) => next_pos + Dir::NX,
         };
 
         let mut can_move = false;
-        if dir.y == 0 {
+        if dir.is_horizontal() {
             // * Boxes are only being moved along the x-axis, so we can use a similar method to pt. 1.
             loop {
-                match map.get(&next_pos) {
+                match map.get(next_pos) {
                     Some(TilePt2::Wall) => break,
                     Some(TilePt2::BoxLeft) => {
                         to_move.insert(next_pos);
@@ -220,7 +153,7 @@ pub fn solve_pt2() -> u32 {
                         break;
                     }
                 }
-                next_pos = next_pos + dir;
+                next_pos += dir;
             }
         } else {
             // * Moving along the y-axis, however, is a bit more complicated...
@@ -232,8 +165,8 @@ pub fn solve_pt2() -> u32 {
                 for &pos in &row {
                     to_move.insert(pos);
                     let next_left = pos + dir;
-                    let next_right = next_left + Pos::PX;
-                    match map.get(&next_left) {
+                    let next_right = next_left + Dir::PX;
+                    match map.get(next_left) {
                         Some(TilePt2::Wall) => {
                             can_move = false;
                             break;
@@ -243,11 +176,11 @@ pub fn solve_pt2() -> u32 {
                             continue;
                         }
                         Some(TilePt2::BoxRight) => {
-                            next_row.insert(next_left + Pos::NX);
+                            next_row.insert(next_left + Dir::NX);
                         }
                         None => {}
                     }
-                    match map.get(&next_right) {
+                    match map.get(next_right) {
                         Some(TilePt2::Wall) => {
                             can_move = false;
                             break;
@@ -268,24 +201,79 @@ pub fn solve_pt2() -> u32 {
         }
 
         if can_move {
-            robot_pos = robot_pos + dir;
+            robot_pos += dir;
             for &box_pos in &to_move {
-                map.remove(&box_pos);
-                map.remove(&(box_pos + Pos::PX));
+                map.remove(box_pos);
+                map.remove(box_pos + Dir::PX);
             }
             for &box_pos in &to_move {
-                map.insert(box_pos + dir, TilePt2::BoxLeft);
-                map.insert(box_pos + dir + Pos::PX, TilePt2::BoxRight);
+                map.set(box_pos + dir, TilePt2::BoxLeft);
+                map.set(box_pos + dir + Dir::PX, TilePt2::BoxRight);
             }
         }
     }
 
     let mut res = 0;
-    for (pos, tile) in map {
+    for (pos, tile) in map.iter() {
         // * The left side of each box will always be the closest to the top-left corner.
-        if tile == TilePt2::BoxLeft {
+        if *tile == TilePt2::BoxLeft {
             res += pos.x + (100 * pos.y);
         }
     }
     return res as u32;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    #[test]
+    fn pt1_small_example() {
+        assert_eq!(solve_pt1(SMALL_EXAMPLE), 2028);
+    }
+
+    #[test]
+    fn pt1_large_example() {
+        assert_eq!(solve_pt1(LARGE_EXAMPLE), 10092);
+    }
+
+    #[test]
+    fn pt2_large_example() {
+        assert_eq!(solve_pt2(LARGE_EXAMPLE), 9021);
+    }
+}
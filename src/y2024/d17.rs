@@ -1,8 +1,5 @@
-use crate::include_input;
 use std::num::ParseIntError;
 
-const INPUT: &str = include_input!("2024", "17");
-
 #[derive(Debug)]
 struct Computer {
     pub program: Vec<u8>,
@@ -20,8 +17,8 @@ impl Computer {
             .parse();
     }
 
-    pub fn load() -> Self {
-        let chars = INPUT.chars();
+    pub fn load(input: &str) -> Self {
+        let chars = input.chars();
         // * Skip "Register A: ", read `reg_a`.
         let mut chars = chars.skip(12);
         let reg_a = Self::read_num(&mut chars).unwrap();
@@ -143,8 +140,8 @@ impl From<u8> for Instruction {
     }
 }
 
-pub fn solve_pt1() -> String {
-    let mut comp = Computer::load();
+pub fn solve_pt1(input: &str) -> String {
+    let mut comp = Computer::load(input);
     let mut out = Vec::new();
 
     while comp.ip < comp.program.len() - 1 {
@@ -156,3 +153,20 @@ pub fn solve_pt1() -> String {
     }
     return out.join(",");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), "4,6,3,5,6,3,5,2,1,0");
+    }
+}
@@ -1,54 +1,12 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    ops::{Add, Sub},
-};
+use std::collections::{HashSet, VecDeque};
 
-use crate::include_input;
+use crate::util::vec2::Vec2;
 
-const INPUT: &str = include_input!("2024", "18");
+type Pos = Vec2<isize>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    pub fn out_of_bounds(&self, bounds: &Self) -> bool {
-        return self.x < 0 || self.y < 0 || self.x > bounds.x || self.y > bounds.y;
-    }
-
-    pub const PX: Self = Self::new(1, 0);
-    pub const PY: Self = Self::new(0, 1);
-    pub const NX: Self = Self::new(-1, 0);
-    pub const NY: Self = Self::new(0, -1);
-
-    pub const ALL_DIRS: [Self; 4] = [Self::PX, Self::PY, Self::NX, Self::NY];
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-fn load_bytes() -> Vec<Pos> {
+pub(super) fn load_bytes(input: &str) -> Vec<Pos> {
     let mut res = Vec::new();
-    for l in INPUT.lines() {
+    for l in input.lines() {
         let mut chars = l.chars();
         let x = chars
             .by_ref()
@@ -67,12 +25,18 @@ fn load_bytes() -> Vec<Pos> {
     return res;
 }
 
-pub fn solve_pt1() -> u32 {
-    const BOUNDS: Pos = Pos::new(70, 70);
-    let bytes = load_bytes();
+/// The maximum position within the memory space (the example input instead uses 6, 6).
+const BOUNDS: Pos = Pos::new(70, 70);
+
+pub fn solve_pt1(input: &str) -> u32 {
+    return shortest_path(input, BOUNDS, 1024);
+}
+
+fn shortest_path(input: &str, bounds: Pos, num_bytes: usize) -> u32 {
+    let bytes = load_bytes(input);
     let mut space = HashSet::new();
 
-    for b in bytes.into_iter().take(1024) {
+    for b in bytes.into_iter().take(num_bytes) {
         space.insert(b);
     }
 
@@ -84,27 +48,30 @@ pub fn solve_pt1() -> u32 {
     while let Some((pos, score)) = stack.pop_front() {
         if !checked.insert(pos)
             || space.contains(&pos)
-            || pos.out_of_bounds(&BOUNDS)
+            || !pos.in_bounds(Pos::ZERO, bounds)
             || score > best_score
         {
             continue;
         }
-        if pos == BOUNDS {
+        if pos == bounds {
             if score < best_score {
                 best_score = score;
             }
             continue;
         }
-        for dir in Pos::ALL_DIRS {
-            stack.push_back((pos + dir, score + 1));
+        for next in pos.neighbours4() {
+            stack.push_back((next, score + 1));
         }
     }
     return best_score;
 }
 
-pub fn solve_pt2() -> String {
-    const BOUNDS: Pos = Pos::new(70, 70);
-    let bytes = load_bytes();
+pub fn solve_pt2(input: &str) -> String {
+    return first_blocking_byte(input, BOUNDS);
+}
+
+fn first_blocking_byte(input: &str, bounds: Pos) -> String {
+    let bytes = load_bytes(input);
     let mut space = HashSet::new();
     let mut checked = HashSet::new();
     let mut stack = Vec::new();
@@ -116,17 +83,57 @@ pub fn solve_pt2() -> String {
         stack.push(Pos::new(0, 0));
 
         while let Some(pos) = stack.pop() {
-            if !checked.insert(pos) || space.contains(&pos) || pos.out_of_bounds(&BOUNDS) {
+            if !checked.insert(pos) || space.contains(&pos) || !pos.in_bounds(Pos::ZERO, bounds) {
                 continue;
             }
-            if pos == BOUNDS {
+            if pos == bounds {
                 continue 'bytes;
             }
-            for dir in Pos::ALL_DIRS {
-                stack.push(pos + dir);
-            }
+            stack.extend(pos.neighbours4());
         }
-        return format!("{},{}", b.x, b.y);
+        return b.to_string();
     }
     panic!("No byte blocks the exit!");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(shortest_path(EXAMPLE, Pos::new(6, 6), 12), 22);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(first_blocking_byte(EXAMPLE, Pos::new(6, 6)), "6,1");
+    }
+}
@@ -1,10 +1,6 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "2");
-
-fn load_reports() -> Vec<Vec<u32>> {
+pub(super) fn load_reports(input: &str) -> Vec<Vec<u32>> {
     let mut res = Vec::new();
-    for line in INPUT.lines() {
+    for line in input.lines() {
         let mut report = Vec::new();
         let mut buffer = String::new();
         for c in line.chars() {
@@ -25,8 +21,8 @@ fn load_reports() -> Vec<Vec<u32>> {
     return res;
 }
 
-pub fn solve_pt1() -> u32 {
-    let reports = load_reports();
+pub fn solve_pt1(input: &str) -> u32 {
+    let reports = load_reports(input);
     let mut res = 0;
 
     for report in reports {
@@ -55,8 +51,8 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
-    let reports = load_reports();
+pub fn solve_pt2(input: &str) -> u32 {
+    let reports = load_reports(input);
     let mut res = 0;
 
     for report in reports {
@@ -110,3 +106,26 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 2);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 4);
+    }
+}
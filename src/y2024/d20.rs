@@ -1,111 +1,46 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::{Add, Mul, Sub},
-};
-
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "20");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    pub fn out_of_bounds(&self, pos: &Self) -> bool {
-        pos.x < 0 || pos.y < 0 || pos.x > self.x || pos.y > self.y
-    }
-
-    pub const PX: Self = Self::new(1, 0);
-    pub const PY: Self = Self::new(0, 1);
-    pub const NX: Self = Self::new(-1, 0);
-    pub const NY: Self = Self::new(0, -1);
-
-    pub const ALL_DIRS: [Self; 4] = [Self::PX, Self::PY, Self::NX, Self::NY];
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl Mul<Pos> for isize {
-    type Output = Pos;
+use std::collections::{HashMap, HashSet};
 
-    fn mul(self, rhs: Pos) -> Self::Output {
-        return Pos::new(self * rhs.x, self * rhs.y);
-    }
-}
+use crate::util::{
+    grid::{Grid, Pos},
+    vec2::Dir,
+};
 
 struct Map {
     pub start_pos: Pos,
     pub end_pos: Pos,
-    pub bounds: Pos,
-    pub walls: HashSet<Pos>,
+    pub walls: Grid<bool>,
 }
 
 impl Map {
-    pub fn load() -> Self {
-        let mut start_pos = Pos::new(0, 0);
-        let mut end_pos = Pos::new(0, 0);
-        let mut bounds = Pos::new(0, 0);
-        let mut walls = HashSet::new();
-
-        for (y, line) in INPUT.lines().enumerate() {
-            for (x, c) in line.char_indices() {
-                let pos = Pos::new(x as isize, y as isize);
-                bounds.x = Ord::max(bounds.x, pos.x);
-                bounds.y = Ord::max(bounds.y, pos.y);
-                match c {
-                    '#' => {
-                        walls.insert(pos);
-                    }
-                    'S' => {
-                        start_pos = pos;
-                    }
-                    'E' => {
-                        end_pos = pos;
-                    }
-                    '.' => {}
-                    _ => panic!("Invalid map input!"),
-                }
-            }
-        }
+    pub fn load(input: &str) -> Self {
+        let grid = Grid::parse(input, |c| {
+            assert!("#SE.".contains(c), "Invalid map input!");
+            return c;
+        });
         return Self {
-            start_pos,
-            end_pos,
-            bounds,
-            walls,
+            start_pos: grid.find(|&c| c == 'S').unwrap(),
+            end_pos: grid.find(|&c| c == 'E').unwrap(),
+            walls: grid.map(|&c| c == '#'),
         };
     }
 }
 
-pub fn solve_pt1() -> u32 {
-    let map = Map::load();
+/// The minimum time a cheat must save to be counted (the example input instead lists cheats of every length).
+const MIN_SAVING: u32 = 100;
+
+pub fn solve_pt1(input: &str) -> u32 {
+    return count_cheats(input, MIN_SAVING);
+}
+
+fn count_cheats(input: &str, min_saving: u32) -> u32 {
+    let map = Map::load(input);
 
     // * `times` stores the time required to get to any position without using a cheat.
     let mut times = HashMap::new();
     let mut stack = vec![(map.start_pos, 0)];
 
     while let Some((pos, time)) = stack.pop() {
-        if map.bounds.out_of_bounds(&pos) || map.walls.contains(&pos) {
+        if map.walls.get(pos).is_none_or(|&wall| wall) {
             // * `pos` is either at the end, in a wall, or out of the map's bounds.
             continue;
         }
@@ -125,8 +60,8 @@ pub fn solve_pt1() -> u32 {
         if pos == map.end_pos {
             continue;
         }
-        for dir in Pos::ALL_DIRS {
-            stack.push((pos + dir, time + 1u32));
+        for next in pos.neighbours4() {
+            stack.push((next, time + 1u32));
         }
     }
 
@@ -140,13 +75,13 @@ pub fn solve_pt1() -> u32 {
         }
     }
 
-    // * We now look for pairs of positions that have the correct gap and result in a `delta_time` that is >= 100.
+    // * We now look for pairs of positions that have the correct gap and result in a `delta_time` that is >= `min_saving`.
     // * Since a gap with no walls between the positions (i.e. a 'regular' path) will only have a `delta_time` of at most 2,
     // * we don't have to explicitly check for a wall between the positions.
 
     // TODO: Figure out a way to get rid of this nested loop, since it's really slow.
 
-    let gaps: HashSet<Pos> = Pos::ALL_DIRS.into_iter().map(|d| 2 * d).collect();
+    let gaps: HashSet<Pos> = Dir::ALL.into_iter().map(|d| d.to_vec() * 2).collect();
     let mut res = 0;
 
     for (&start_pos, &start_time) in &inv_times {
@@ -154,7 +89,7 @@ pub fn solve_pt1() -> u32 {
             // * -2 for the time taking moving through the removed wall.
             if let Some(delta_time) = end_time.checked_sub(start_time + 2) {
                 let delta_pos = end_pos - start_pos;
-                if gaps.contains(&delta_pos) && delta_time >= 100 {
+                if gaps.contains(&delta_pos) && delta_time >= min_saving {
                     res += 1;
                 }
             }
@@ -163,15 +98,19 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
-    let map = Map::load();
+pub fn solve_pt2(input: &str) -> u32 {
+    return count_long_cheats(input, MIN_SAVING);
+}
+
+fn count_long_cheats(input: &str, min_saving: u32) -> u32 {
+    let map = Map::load(input);
 
     // * `times` stores the time required to get to any position without using a cheat.
     let mut times = HashMap::new();
     let mut stack = vec![(map.start_pos, 0)];
 
     while let Some((pos, time)) = stack.pop() {
-        if map.bounds.out_of_bounds(&pos) || map.walls.contains(&pos) {
+        if map.walls.get(pos).is_none_or(|&wall| wall) {
             // * `pos` is either at the end, in a wall, or out of the map's bounds.
             continue;
         }
@@ -191,8 +130,8 @@ pub fn solve_pt2() -> u32 {
         if pos == map.end_pos {
             continue;
         }
-        for dir in Pos::ALL_DIRS {
-            stack.push((pos + dir, time + 1u32));
+        for next in pos.neighbours4() {
+            stack.push((next, time + 1u32));
         }
     }
 
@@ -206,7 +145,7 @@ pub fn solve_pt2() -> u32 {
         }
     }
 
-    // * We now look for pairs of positions that have the correct gap and result in a `delta_time` that is >= 100.
+    // * We now look for pairs of positions that have the correct gap and result in a `delta_time` that is >= `min_saving`.
     // * Instead of part 1's 'gap' definition, we'll just use the 'taxicab' distance to determine the the time used during the cheat.
 
     // TODO: Figure out a way to get rid of this nested loop, since it's really slow.
@@ -214,11 +153,10 @@ pub fn solve_pt2() -> u32 {
     let mut res = 0;
     for (&start_pos, &start_time) in &inv_times {
         for (&end_pos, &end_time) in &inv_times {
-            let delta_pos = end_pos - start_pos;
-            let dist = delta_pos.x.abs() + delta_pos.y.abs();
+            let dist = start_pos.manhattan(end_pos);
             // * -dist for the time taking moving through the removed wall(s).
             if let Some(delta_time) = end_time.checked_sub(start_time + dist as u32) {
-                if dist <= 20 && delta_time >= 100 {
+                if dist <= 20 && delta_time >= min_saving {
                     res += 1;
                 }
             }
@@ -226,3 +164,45 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(count_cheats(EXAMPLE, 20), 5);
+    }
+
+    #[test]
+    fn pt1_example_min_64() {
+        assert_eq!(count_cheats(EXAMPLE, 64), 1);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(count_long_cheats(EXAMPLE, 50), 285);
+    }
+
+    #[test]
+    fn pt2_example_min_76() {
+        assert_eq!(count_long_cheats(EXAMPLE, 76), 3);
+    }
+}
@@ -1,8 +1,5 @@
-use crate::include_input;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_input!("2024", "22");
-
 fn next_secret(mut num: u64) -> u64 {
     num = (num ^ (num * 64)) % 16777216;
     num = (num ^ (num / 32)) % 16777216;
@@ -14,13 +11,13 @@ fn get_price(num: u64) -> i32 {
     return num.to_string().pop().unwrap().to_digit(10).unwrap() as i32;
 }
 
-fn load_input() -> Vec<u64> {
-    return INPUT.lines().map(|l| l.parse().unwrap()).collect();
+pub(super) fn load_input(input: &str) -> Vec<u64> {
+    return input.lines().map(|l| l.parse().unwrap()).collect();
 }
 
-pub fn solve_pt1() -> u64 {
+pub fn solve_pt1(input: &str) -> u64 {
     let mut res = 0;
-    let input = load_input();
+    let input = load_input(input);
 
     for mut num in input {
         for _ in 0..2000 {
@@ -31,8 +28,8 @@ pub fn solve_pt1() -> u64 {
     return res;
 }
 
-pub fn solve_pt2() -> i32 {
-    let input = load_input();
+pub fn solve_pt2(input: &str) -> i32 {
+    let input = load_input(input);
 
     let mut prices = Vec::with_capacity(2000);
     let mut changes = Vec::with_capacity(2000);
@@ -70,3 +67,30 @@ pub fn solve_pt2() -> i32 {
     }
     return sequences.into_values().max().unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PT1: &str = "\
+1
+10
+100
+2024";
+
+    const EXAMPLE_PT2: &str = "\
+1
+2
+3
+2024";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE_PT1), 37327623);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE_PT2), 23);
+    }
+}
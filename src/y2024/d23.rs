@@ -1,10 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "23");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Computer {
     pub first: char,
     pub second: char,
@@ -15,9 +11,9 @@ impl Computer {
         Self { first, second }
     }
 
-    pub fn load_connections() -> Vec<(Self, Self)> {
+    pub fn load_connections(input: &str) -> Vec<(Self, Self)> {
         let mut connections = Vec::new();
-        for line in INPUT.lines() {
+        for line in input.lines() {
             let chars = line.chars().collect::<Vec<_>>();
             let a = Self::new(chars[0], chars[1]);
             let b = Self::new(chars[3], chars[4]);
@@ -27,8 +23,8 @@ impl Computer {
     }
 }
 
-pub fn solve_pt1() -> u32 {
-    let connections = Computer::load_connections();
+pub fn solve_pt1(input: &str) -> u32 {
+    let connections = Computer::load_connections(input);
     // * `connected_to` stores a list of every computer along with its 'primary' connections (i.e. each connection in the provided input).
     let mut connected_to: HashMap<Computer, HashSet<Computer>> =
         HashMap::with_capacity(connections.len());
@@ -72,8 +68,8 @@ pub fn solve_pt1() -> u32 {
     return triplets.len() as u32;
 }
 
-pub fn solve_pt2() -> String {
-    let connections = Computer::load_connections();
+pub fn solve_pt2(input: &str) -> String {
+    let connections = Computer::load_connections(input);
     // * `connected_to` stores a list of every computer along with its 'primary' connections (i.e. each connection in the provided input).
     let mut connected_to: HashMap<Computer, HashSet<Computer>> =
         HashMap::with_capacity(connections.len());
@@ -95,7 +91,11 @@ pub fn solve_pt2() -> String {
     let mut password = String::new();
     let mut group = HashMap::new();
     let mut stack = Vec::new();
-    for &start in connected_to.keys() {
+    // * Which group is found from each computer depends on the order that its connections are tried in,
+    // * so they're sorted to give the same answer every time (rather than depending on the order of the `HashMap`).
+    let mut computers = connected_to.keys().copied().collect::<Vec<_>>();
+    computers.sort();
+    for start in computers {
         group.clear();
         stack.clear();
         stack.push(start);
@@ -109,9 +109,9 @@ pub fn solve_pt2() -> String {
             }
             let connections = &connected_to[&from];
             group.insert(from, connections);
-            for to in connections {
-                stack.push(*to);
-            }
+            let mut connections = connections.iter().copied().collect::<Vec<_>>();
+            connections.sort_by(|a, b| b.cmp(a));
+            stack.extend(connections);
         }
         if group.len() > largest {
             largest = group.len();
@@ -125,3 +125,52 @@ pub fn solve_pt2() -> String {
     }
     return password;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 7);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), "co,de,ka,ta");
+    }
+}
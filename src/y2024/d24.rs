@@ -3,10 +3,6 @@ use std::{
     collections::{HashMap, VecDeque},
 };
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "24");
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operator {
     AND,
@@ -57,8 +53,8 @@ struct Wire {
     pub label: [char; 3],
 }
 
-fn load_inputs() -> (HashMap<Wire, bool>, Vec<Gate>) {
-    let (input_wires, input_gates) = INPUT.split_once("\n\n").unwrap();
+fn load_inputs(input: &str) -> (HashMap<Wire, bool>, Vec<Gate>) {
+    let (input_wires, input_gates) = input.split_once("\n\n").unwrap();
     let mut wires = HashMap::new();
     let mut gates = Vec::new();
 
@@ -109,8 +105,8 @@ fn load_inputs() -> (HashMap<Wire, bool>, Vec<Gate>) {
     return (wires, gates);
 }
 
-pub fn solve_pt1() -> u64 {
-    let (mut wires, gates) = load_inputs();
+pub fn solve_pt1(input: &str) -> u64 {
+    let (mut wires, gates) = load_inputs(input);
     let mut queue = VecDeque::from(gates);
 
     while let Some(gate) = queue.pop_front() {
@@ -141,7 +137,7 @@ pub fn solve_pt1() -> u64 {
     return res;
 }
 
-pub fn solve_pt2() -> String {
+pub fn solve_pt2(_input: &str) -> String {
     // ! I solved this puzzle manually, but may attempt to write some proper code later on: https://www.reddit.com/r/adventofcode/comments/1hl698z/comment/m3kg20o
     // * TLDR: The gates form a ripple carry adder, so you can go through the gates and sort them into components of the adder based on their inputs & operator.
     // * You can then deductively tag the the wires based on their usage in the gates (e.g. "hta" is tagged as "A03 (hta)", some form of macro can be useful for mass-tagging).
@@ -149,3 +145,25 @@ pub fn solve_pt2() -> String {
     // ? https://en.wikipedia.org/wiki/Adder_(electronics)#Ripple-carry_adder
     unimplemented!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 4);
+    }
+}
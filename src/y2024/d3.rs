@@ -1,10 +1,6 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "3");
-
-pub fn solve_pt1() -> u32 {
+pub fn solve_pt1(input: &str) -> u32 {
     let mut res = 0;
-    for substr in INPUT.split("mul(") {
+    for substr in input.split("mul(") {
         let mut chars = substr.chars();
         let mut num1 = String::new();
         let mut invalid_char = false;
@@ -38,12 +34,12 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
+pub fn solve_pt2(input: &str) -> u32 {
     let mut res = 0;
     let mut mul_enabled = true;
     let mut stack = String::new();
 
-    let mut chars = INPUT.chars().rev().collect::<Vec<_>>();
+    let mut chars = input.chars().rev().collect::<Vec<_>>();
 
     while let Some(c) = chars.pop() {
         stack.push(c);
@@ -110,3 +106,24 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PT1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+    const EXAMPLE_PT2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE_PT1), 161);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE_PT2), 48);
+    }
+}
@@ -1,16 +1,13 @@
-use crate::include_input;
 use std::collections::{HashMap, HashSet};
 
-const INPUT: &str = include_input!("2024", "5");
-
 struct Pages {
     pub ordering: HashMap<u32, HashSet<u32>>,
     pub updates: Vec<Vec<u32>>,
 }
 
 impl Pages {
-    pub fn load() -> Self {
-        let (ordering_input, updates_input) = INPUT.split_once("\n\n").unwrap();
+    pub fn load(input: &str) -> Self {
+        let (ordering_input, updates_input) = input.split_once("\n\n").unwrap();
 
         let mut ordering: HashMap<u32, HashSet<u32>> = HashMap::new();
         for line in ordering_input.lines() {
@@ -38,8 +35,8 @@ impl Pages {
     }
 }
 
-pub fn solve_pt1() -> u32 {
-    let pages = Pages::load();
+pub fn solve_pt1(input: &str) -> u32 {
+    let pages = Pages::load(input);
     let mut res = 0;
 
     for update in pages.updates {
@@ -48,7 +45,10 @@ pub fn solve_pt1() -> u32 {
         'i: for i in 0..len {
             // * Iterate through every page number in this update.
             let must_precede = update[i];
-            let order_check = &pages.ordering[&must_precede];
+            let Some(order_check) = pages.ordering.get(&must_precede) else {
+                // * There are no rules for which pages must come after `must_precede`.
+                continue;
+            };
             for j in 0..i {
                 // * Iterate through every page number before the current one.
                 let must_succeed = update[j];
@@ -66,8 +66,8 @@ pub fn solve_pt1() -> u32 {
     return res;
 }
 
-pub fn solve_pt2() -> u32 {
-    let pages = Pages::load();
+pub fn solve_pt2(input: &str) -> u32 {
+    let pages = Pages::load(input);
     let mut res = 0;
 
     for mut update in pages.updates {
@@ -77,7 +77,10 @@ pub fn solve_pt2() -> u32 {
             for i in 0..len {
                 // * Iterate through every page number in this update.
                 let must_precede = update[i];
-                let order_check = &pages.ordering[&must_precede];
+                let Some(order_check) = pages.ordering.get(&must_precede) else {
+                    // * There are no rules for which pages must come after `must_precede`.
+                    continue;
+                };
                 for j in 0..i {
                     // * Iterate through every page number before the current one.
                     let must_succeed = update[j];
@@ -99,3 +102,48 @@ pub fn solve_pt2() -> u32 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 143);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 123);
+    }
+}
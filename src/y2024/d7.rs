@@ -1,7 +1,3 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "7");
-
 #[derive(Debug)]
 struct Equation {
     pub result: u64,
@@ -9,9 +5,9 @@ struct Equation {
 }
 
 impl Equation {
-    pub fn load() -> Vec<Self> {
+    pub fn load(input: &str) -> Vec<Self> {
         let mut equations = Vec::new();
-        for line in INPUT.lines() {
+        for line in input.lines() {
             let mut chars = line.chars();
             // * Read the equation's result.
             let result = chars
@@ -60,8 +56,8 @@ impl Operation {
     }
 }
 
-pub fn solve_pt1() -> u64 {
-    let equations = Equation::load();
+pub fn solve_pt1(input: &str) -> u64 {
+    let equations = Equation::load(input);
     let mut res = 0;
     let mut ops = Vec::new();
     for eq in equations {
@@ -99,8 +95,8 @@ pub fn solve_pt1() -> u64 {
     return res;
 }
 
-pub fn solve_pt2() -> u64 {
-    let equations = Equation::load();
+pub fn solve_pt2(input: &str) -> u64 {
+    let equations = Equation::load(input);
     let mut res = 0;
     let mut ops = Vec::new();
     for eq in equations {
@@ -142,3 +138,29 @@ pub fn solve_pt2() -> u64 {
     }
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 3749);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 11387);
+    }
+}
@@ -1,78 +1,31 @@
-use std::{
-    collections::{HashMap, HashSet},
-    ops::{Add, AddAssign, Sub},
-};
+use std::collections::{HashMap, HashSet};
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "8");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl Pos {
-    pub const fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl AddAssign for Pos {
-    fn add_assign(&mut self, rhs: Self) {
-        *self = self.add(rhs);
-    }
-}
+use crate::util::grid::{Grid, Pos};
 
 struct Map {
-    pub bounds: Pos,
+    pub grid: Grid<char>,
     pub nodes: HashMap<char, Vec<Pos>>,
 }
 
 impl Map {
-    pub fn load() -> Self {
-        let mut bounds = Pos::new(0, 0);
+    pub fn load(input: &str) -> Self {
+        let grid = Grid::parse(input, |c| c);
         let mut nodes: HashMap<_, Vec<_>> = HashMap::new();
-        for (y, line) in INPUT.lines().enumerate() {
-            for (x, c) in line.char_indices() {
-                let pos = Pos::new(x as isize, y as isize);
-                bounds = pos;
-                if c != '.' {
-                    if let Some(node) = nodes.get_mut(&c) {
-                        node.push(pos);
-                    } else {
-                        nodes.insert(c, vec![pos]);
-                    }
-                }
+        for (pos, &c) in grid.iter() {
+            if c != '.' {
+                nodes.entry(c).or_default().push(pos);
             }
         }
-        return Self { bounds, nodes };
+        return Self { grid, nodes };
     }
 
     pub fn in_bounds(&self, pos: &Pos) -> bool {
-        pos.x >= 0 && pos.y >= 0 && pos.x <= self.bounds.x && pos.y <= self.bounds.y
+        self.grid.in_bounds(*pos)
     }
 }
 
-pub fn solve_pt1() -> u32 {
-    let map = Map::load();
+pub fn solve_pt1(input: &str) -> u32 {
+    let map = Map::load(input);
     let mut antinodes = HashSet::new();
 
     for (_, nodes) in &map.nodes {
@@ -100,8 +53,8 @@ pub fn solve_pt1() -> u32 {
     return antinodes.len() as u32;
 }
 
-pub fn solve_pt2() -> u32 {
-    let map = Map::load();
+pub fn solve_pt2(input: &str) -> u32 {
+    let map = Map::load(input);
     let mut antinodes = HashSet::new();
 
     for (_, nodes) in &map.nodes {
@@ -125,3 +78,32 @@ pub fn solve_pt2() -> u32 {
     }
     return antinodes.len() as u32;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 14);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 34);
+    }
+}
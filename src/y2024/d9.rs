@@ -1,9 +1,5 @@
 use std::{iter::repeat_n, mem::replace};
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2024", "9");
-
 #[derive(Debug, Clone)]
 struct BlockSpan {
     pub length: usize,
@@ -25,8 +21,8 @@ impl BlockSpan {
         }
     }
 
-    pub fn load() -> Vec<Self> {
-        let disk_map = INPUT.chars().map(|c| c.to_digit(10).unwrap() as usize);
+    pub fn load(input: &str) -> Vec<Self> {
+        let disk_map = input.chars().map(|c| c.to_digit(10).unwrap() as usize);
         let mut spans = Vec::new();
 
         let mut file_id = 0;
@@ -52,8 +48,8 @@ impl BlockSpan {
     }
 }
 
-pub fn solve_pt1() -> u64 {
-    let mut blocks = BlockSpan::load()
+pub fn solve_pt1(input: &str) -> u64 {
+    let mut blocks = BlockSpan::load(input)
         .into_iter()
         .flat_map(BlockSpan::expand)
         .collect::<Vec<_>>();
@@ -88,8 +84,8 @@ pub fn solve_pt1() -> u64 {
     return res;
 }
 
-pub fn solve_pt2() -> u64 {
-    let mut spans = BlockSpan::load();
+pub fn solve_pt2(input: &str) -> u64 {
+    let mut spans = BlockSpan::load(input);
 
     let mut prev_id = u64::MAX;
     for file_idx in (0..spans.len()).rev() {
@@ -129,6 +125,23 @@ pub fn solve_pt2() -> u64 {
     for (block_idx, file_id) in iter {
         res += block_idx as u64 * file_id;
     }
-    assert_eq!(res, 6547228115826);
     return res;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 1928);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 2858);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{day, solution::Day};
+
 pub mod d1;
 pub mod d10;
 pub mod d11;
@@ -19,3 +21,28 @@ pub mod d6;
 pub mod d7;
 pub mod d8;
 pub mod d9;
+
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, "Historian Hysteria", d1::solve_pt1, d1::solve_pt2; parse = d1::load_lists),
+    day!(2024, 2, "Red-Nosed Reports", d2::solve_pt1, d2::solve_pt2; parse = d2::load_reports),
+    day!(2024, 3, "Mull It Over", d3::solve_pt1, d3::solve_pt2),
+    day!(2024, 4, "Ceres Search", d4::solve_pt1, d4::solve_pt2),
+    day!(2024, 5, "Print Queue", d5::solve_pt1, d5::solve_pt2),
+    day!(2024, 6, "Guard Gallivant", d6::solve_pt1, d6::solve_pt2),
+    day!(2024, 7, "Bridge Repair", d7::solve_pt1, d7::solve_pt2),
+    day!(2024, 8, "Resonant Collinearity", d8::solve_pt1, d8::solve_pt2),
+    day!(2024, 9, "Disk Fragmenter", d9::solve_pt1, d9::solve_pt2),
+    day!(2024, 10, "Hoof It", d10::solve_pt1, d10::solve_pt2),
+    day!(2024, 11, "Plutonian Pebbles", d11::solve_pt1, d11::solve_pt2; parse = d11::load_stones),
+    day!(2024, 12, "Garden Groups", d12::solve_pt1, d12::solve_pt2),
+    day!(2024, 13, "Claw Contraption", d13::solve_pt1, d13::solve_pt2),
+    day!(2024, 14, "Restroom Redoubt", d14::solve_pt1, d14::solve_pt2),
+    day!(2024, 15, "Warehouse Woes", d15::solve_pt1, d15::solve_pt2; parse = d15::load_inputs),
+    day!(2024, 17, "Chronospatial Computer", d17::solve_pt1), // TODO: pt. 2
+    day!(2024, 18, "RAM Run", d18::solve_pt1, d18::solve_pt2; parse = d18::load_bytes),
+    day!(2024, 20, "Race Condition", d20::solve_pt1, d20::solve_pt2),
+    day!(2024, 22, "Monkey Market", d22::solve_pt1, d22::solve_pt2; parse = d22::load_input),
+    day!(2024, 23, "LAN Party", d23::solve_pt1, d23::solve_pt2),
+    day!(2024, 24, "Crossed Wires", d24::solve_pt1, d24::solve_pt2),
+];
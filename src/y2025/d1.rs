@@ -1,7 +1,3 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "1");
-
 #[derive(Debug)]
 struct Dial(i32);
 
@@ -37,11 +33,11 @@ impl Dial {
     }
 }
 
-pub fn solve_pt1() -> u32 {
+pub fn solve_pt1(input: &str) -> u32 {
     let mut count = 0;
     let mut dial = Dial(50);
 
-    for l in INPUT.lines() {
+    for l in input.lines() {
         let rot = Dial::parse_rot(l);
         dial.apply_rot(rot);
         if dial.0 == 0 {
@@ -51,13 +47,40 @@ pub fn solve_pt1() -> u32 {
     return count;
 }
 
-pub fn solve_pt2() -> u32 {
+pub fn solve_pt2(input: &str) -> u32 {
     let mut count = 0;
     let mut dial = Dial(50);
 
-    for l in INPUT.lines() {
+    for l in input.lines() {
         let rot = Dial::parse_rot(l);
         count += dial.apply_rot(rot);
     }
     return count;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 3);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 6);
+    }
+}
@@ -1,19 +1,15 @@
 use std::ops::RangeInclusive;
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "2");
-
-fn load_ranges() -> Vec<RangeInclusive<u64>> {
-    return INPUT
+pub(super) fn load_ranges(input: &str) -> Vec<RangeInclusive<u64>> {
+    return input
         .split(',')
         .filter_map(|r| r.split_once('-'))
         .map(|(l, u)| l.parse().unwrap()..=u.parse().unwrap())
         .collect();
 }
 
-pub fn solve_pt1() -> u64 {
-    let ranges = load_ranges();
+pub fn solve_pt1(input: &str) -> u64 {
+    let ranges = load_ranges(input);
     let mut sum = 0;
 
     for range in ranges {
@@ -29,8 +25,8 @@ pub fn solve_pt1() -> u64 {
     return sum;
 }
 
-pub fn solve_pt2() -> u64 {
-    let ranges = load_ranges();
+pub fn solve_pt2(input: &str) -> u64 {
+    let ranges = load_ranges(input);
     let mut sum = 0;
 
     for range in ranges {
@@ -53,3 +49,21 @@ pub fn solve_pt2() -> u64 {
     }
     return sum;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 1227775554);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 4174379265);
+    }
+}
@@ -1,14 +1,10 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "3");
-
-fn load_banks() -> Vec<Vec<char>> {
-    return INPUT.lines().map(|l| l.chars().collect()).collect();
+fn load_banks(input: &str) -> Vec<Vec<char>> {
+    return input.lines().map(|l| l.chars().collect()).collect();
 }
 
-pub fn solve_pt1() -> u32 {
+pub fn solve_pt1(input: &str) -> u32 {
     let mut sum = 0;
-    for bank in load_banks() {
+    for bank in load_banks(input) {
         let mut best_joltage = 0;
         let len = bank.len();
         for i in 0..(len - 1) {
@@ -24,15 +20,31 @@ pub fn solve_pt1() -> u32 {
     return sum;
 }
 
-pub fn solve_pt2() -> u64 {
+pub fn solve_pt2(_input: &str) -> u64 {
     unimplemented!();
     // ? https://www.reddit.com/r/adventofcode/comments/1pd5hm5/2025_day_3_my_approach_visualized/
 
     // const NUM_DIGITS: usize = 12;
     // let mut sum = 0;
 
-    // for mut bank in load_banks() {
+    // for mut bank in load_banks(input) {
 
     // }
     // return sum;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 357);
+    }
+}
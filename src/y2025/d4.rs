@@ -1,85 +1,15 @@
-use std::{
-    collections::HashSet,
-    ops::{Add, Sub},
-};
+use crate::util::grid::Grid;
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "4");
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
-    pub x: usize,
-    pub y: usize,
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl Sub for Pos {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl Pos {
-    #[inline]
-    pub const fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-
-    const PX: Self = Self::new(1, 0);
-    const PY: Self = Self::new(0, 1);
-
-    pub fn neighbours(&self) -> Vec<Self> {
-        let mut neighbours = Vec::new();
-        neighbours.push(*self + Self::PX);
-        neighbours.push(*self + Self::PY);
-        neighbours.push(*self + Self::PX + Self::PY);
-        if self.x > 0 && self.y > 0 {
-            neighbours.push(*self - Self::PX - Self::PY);
-        }
-        if self.x > 0 {
-            neighbours.push(*self - Self::PX);
-            neighbours.push(*self - Self::PX + Self::PY);
-        }
-        if self.y > 0 {
-            neighbours.push(*self - Self::PY);
-            neighbours.push(*self - Self::PY + Self::PX);
-        }
-        return neighbours;
-    }
-}
-
-fn load_grid() -> HashSet<Pos> {
-    let mut grid = HashSet::new();
-    for (y, l) in INPUT.lines().enumerate() {
-        for (x, c) in l.chars().enumerate() {
-            if c == '@' {
-                let pos = Pos::new(x, y);
-                assert!(grid.insert(pos));
-            }
-        }
-    }
-    return grid;
+/// Whether each position of the grid has a roll of paper.
+pub(super) fn load_grid(input: &str) -> Grid<bool> {
+    return Grid::parse(input, |c| c == '@');
 }
 
-pub fn solve_pt1() -> u32 {
-    let grid = load_grid();
+pub fn solve_pt1(input: &str) -> u32 {
+    let grid = load_grid(input);
     let mut count = 0;
-    for pos in &grid {
-        let c = pos
-            .neighbours()
-            .into_iter()
-            .filter(|n| grid.contains(n))
-            .count();
+    for pos in grid.find_all(|&roll| roll) {
+        let c = grid.neighbours8(pos).filter(|(_, &roll)| roll).count();
         if c < 4 {
             count += 1;
         }
@@ -87,19 +17,15 @@ pub fn solve_pt1() -> u32 {
     return count;
 }
 
-pub fn solve_pt2() -> u32 {
-    let mut grid = load_grid();
-    let mut remove = HashSet::new();
+pub fn solve_pt2(input: &str) -> u32 {
+    let mut grid = load_grid(input);
+    let mut remove = Vec::new();
     let mut count = 0;
     loop {
-        for pos in &grid {
-            let c = pos
-                .neighbours()
-                .into_iter()
-                .filter(|n| grid.contains(n))
-                .count();
+        for pos in grid.find_all(|&roll| roll) {
+            let c = grid.neighbours8(pos).filter(|(_, &roll)| roll).count();
             if c < 4 {
-                remove.insert(*pos);
+                remove.push(pos);
             }
         }
 
@@ -108,9 +34,37 @@ pub fn solve_pt2() -> u32 {
             break;
         } else {
             count += len;
-            grid.retain(|p| !remove.contains(p));
-            remove.clear();
+            for pos in remove.drain(..) {
+                grid[pos] = false;
+            }
         }
     }
     return count;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 13);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 43);
+    }
+}
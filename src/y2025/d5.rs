@@ -1,14 +1,10 @@
 use std::ops::RangeInclusive;
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "5");
-
-fn load_input() -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
+pub(super) fn load_input(input: &str) -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
     let mut ranges = Vec::new();
     let mut ids = Vec::new();
 
-    let (a, b) = INPUT.split_once("\n\n").unwrap();
+    let (a, b) = input.split_once("\n\n").unwrap();
 
     for l in a.lines() {
         let (lower, upper) = l.split_once('-').unwrap();
@@ -23,8 +19,8 @@ fn load_input() -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
     return (ranges, ids);
 }
 
-pub fn solve_pt1() -> u32 {
-    let (ranges, ids) = load_input();
+pub fn solve_pt1(input: &str) -> u32 {
+    let (ranges, ids) = load_input(input);
     let mut count = 0;
     for id in &ids {
         let fresh = ranges.iter().any(|r| r.contains(id));
@@ -35,8 +31,8 @@ pub fn solve_pt1() -> u32 {
     return count;
 }
 
-pub fn solve_pt2() -> u64 {
-    let (mut ranges, _) = load_input();
+pub fn solve_pt2(input: &str) -> u64 {
+    let (mut ranges, _) = load_input(input);
     let mut found_merge = true;
 
     'M: while found_merge {
@@ -69,3 +65,31 @@ pub fn solve_pt2() -> u64 {
     }
     return ranges.into_iter().flatten().count() as u64;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 3);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 14);
+    }
+}
@@ -1,7 +1,3 @@
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "6");
-
 enum Operation {
     Sum,
     Product,
@@ -24,8 +20,8 @@ impl Operation {
     }
 }
 
-fn load_worksheet() -> (Vec<Vec<char>>, Vec<char>) {
-    let lines = INPUT.lines().collect::<Vec<_>>();
+pub(super) fn load_worksheet(input: &str) -> (Vec<Vec<char>>, Vec<char>) {
+    let lines = input.lines().collect::<Vec<_>>();
     let (ops, vals) = lines.split_last().unwrap();
 
     let ops = ops
@@ -40,8 +36,8 @@ fn load_worksheet() -> (Vec<Vec<char>>, Vec<char>) {
     return (vals, ops);
 }
 
-pub fn solve_pt1() -> u64 {
-    let (vals, ops) = load_worksheet();
+pub fn solve_pt1(input: &str) -> u64 {
+    let (vals, ops) = load_worksheet(input);
     let num_rows = vals.len();
 
     let vals = vals
@@ -76,8 +72,8 @@ pub fn solve_pt1() -> u64 {
     return sum;
 }
 
-pub fn solve_pt2() -> u64 {
-    let (vals, ops) = load_worksheet();
+pub fn solve_pt2(input: &str) -> u64 {
+    let (vals, ops) = load_worksheet(input);
 	let num_rows = vals.len();
     let num_columns = vals.iter().map(|s| s.len()).max().unwrap();
     
@@ -108,4 +104,26 @@ pub fn solve_pt2() -> u64 {
         }
     }
     return sum;
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "123 328  51 64 \n",
+        " 45 64  387 23 \n",
+        "  6 98  215 314\n",
+        "*   +   *   +  ",
+    );
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 4277556);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 3263827);
+    }
+}
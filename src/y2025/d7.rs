@@ -1,43 +1,25 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::include_input;
+use crate::util::{grid::Pos, sparse_grid::SparseGrid};
 
-const INPUT: &str = include_input!("2025", "7");
-
-fn load_diagram() -> (usize, Vec<HashSet<usize>>) {
-    let mut lines = INPUT.lines().map(|l| l.char_indices());
-
-    let entrance = lines
-        .next()
-        .unwrap()
-        .find(|(_, c)| *c == 'S')
-        .unwrap()
-        .0;
-
-    let mut splitters = Vec::new();
-    for l in lines {
-        let mut line_splitters = HashSet::new();
-        for (i, c) in l {
-            if c == '^' {
-                line_splitters.insert(i);
-            }
-        }
-        splitters.push(line_splitters);
-    }
-
-    return (entrance, splitters);
+/// Load the entrance and the splitters, along with the last row of the diagram.
+pub(super) fn load_diagram(input: &str) -> (Pos, SparseGrid<char>, isize) {
+    let diagram = SparseGrid::parse(input, |c| (c != '.').then_some(c));
+    let entrance = diagram.find(|&c| c == 'S').unwrap();
+    let last_row = input.lines().count() as isize - 1;
+    return (entrance, diagram, last_row);
 }
 
-pub fn solve_pt1() -> u32 {
-    let (entrance, splitters) = load_diagram();
+pub fn solve_pt1(input: &str) -> u32 {
+    let (entrance, diagram, last_row) = load_diagram(input);
 
-    let mut beams = HashSet::from([entrance]);
+    let mut beams = HashSet::from([entrance.x]);
     let mut next_beams = HashSet::new();
     let mut count = 0;
 
-    for line_splitters in splitters {
+    for y in (entrance.y + 1)..=last_row {
         for b in &beams {
-            if line_splitters.contains(b) {
+            if diagram.get(Pos::new(*b, y)) == Some(&'^') {
                 next_beams.insert(b - 1);
                 next_beams.insert(b + 1);
                 count += 1;
@@ -51,13 +33,13 @@ pub fn solve_pt1() -> u32 {
     return count;
 }
 
-pub fn solve_pt2() -> u64 {
-    let (entrance, splitters) = load_diagram();
+pub fn solve_pt2(input: &str) -> u64 {
+    let (entrance, diagram, last_row) = load_diagram(input);
 
-    let mut beams = HashMap::from([(entrance, 1)]);
+    let mut beams = HashMap::from([(entrance.x, 1)]);
     let mut next_beams = HashMap::new();
 
-    fn add_beam(map: &mut HashMap<usize, usize>, idx: usize, count: usize) {
+    fn add_beam(map: &mut HashMap<isize, usize>, idx: isize, count: usize) {
         if let Some(next_count) = map.get_mut(&idx) {
             *next_count += count;
         } else {
@@ -65,9 +47,9 @@ pub fn solve_pt2() -> u64 {
         }
     }
 
-    for line_splitters in splitters {
+    for y in (entrance.y + 1)..=last_row {
         for (b, c) in &beams {
-            if line_splitters.contains(b) {
+            if diagram.get(Pos::new(*b, y)) == Some(&'^') {
                 add_beam(&mut next_beams, b - 1, *c);
                 add_beam(&mut next_beams, b + 1, *c);
             } else {
@@ -78,4 +60,37 @@ pub fn solve_pt2() -> u64 {
         next_beams.clear();
     }
     return beams.into_values().sum::<usize>().try_into().unwrap();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(solve_pt1(EXAMPLE), 21);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 40);
+    }
+}
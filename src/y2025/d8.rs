@@ -1,133 +1,37 @@
-use std::{collections::{HashMap, HashSet}, fmt::Display};
+use crate::util::{disjoint_set::DisjointSet, kd_tree::KdTree, vec3::Vec3};
 
-use crate::include_input;
-
-const INPUT: &str = include_input!("2025", "8");
-
-#[derive(Debug, Clone, Copy)]
-struct Pos {
-    pub x: u64,
-    pub y: u64,
-    pub z: u64,
-}
-
-impl Pos {
-    pub const fn new(x: u64, y: u64, z: u64) -> Self {
-        Self { x, y, z }
-    }
-
-    pub fn dist_squared(a: Self, b: Self) -> u64 {
-        // * Square of the Euclidean distance. Used for sorting a list of positions.
-        let d = Pos::new(
-            u64::abs_diff(a.x, b.x),
-            u64::abs_diff(a.y, b.y), 
-            u64::abs_diff(a.z, b.z),
-        );
-        return (d.x * d.x) + (d.y * d.y) + (d.z * d.z);
-    }
-}
-
-impl Display for Pos {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{},{},{}", self.x, self.y, self.z)
-    }
-}
+type Pos = Vec3<u64>;
 
+/// The circuits formed by connecting junction boxes, which are referred to by their index.
 struct Circuits {
-    map: HashMap<usize, usize>,
-    sizes: HashMap<usize, usize>,
-    connections: HashSet<(usize, usize)>,
-    next_circuit_id: usize,
+    junctions: DisjointSet,
 }
 
 impl Circuits {
-    pub fn new(capacity: usize) -> Self {
+    pub fn new(junction_count: usize) -> Self {
         Self {
-            map: HashMap::with_capacity(capacity),
-            sizes: HashMap::with_capacity(capacity),
-            connections: HashSet::with_capacity(capacity),
-            next_circuit_id: 0,
-        }
-    }
-
-    pub fn same_circuit(&self, ja: usize, jb: usize) -> bool {
-        let ma = self.map.get(&ja);
-        let mb = self.map.get(&jb);
-        if let (Some(ma), Some(mb)) = (ma, mb) {
-            return ma == mb;
-        } else {
-            return false;
-        }
-    }
-
-    pub fn get_sizes_sorted(&self, n: usize) -> impl Iterator<Item = usize> + use<'_> {
-        let mut res = self
-            .sizes
-            .values()
-            .collect::<Vec<_>>();
-        res.sort_unstable();
-        return res
-            .into_iter()
-            .rev()
-            .take(n)
-            .copied();
-    }
-
-    pub fn connect(&mut self, ja: usize, jb: usize) {
-        self.connections.insert((ja, jb));
-        let ma = self.map.get(&ja).copied();
-        let mb = self.map.get(&jb).copied();
-
-        match (ma, mb) {
-            (None, None) => self.new_circuit(ja, jb),
-            (Some(ca), None) => self.add_to_circuit(ca, jb),
-            (None, Some(cb)) => self.add_to_circuit(cb, ja),
-            (Some(ca), Some(cb)) => self.merge_circuits(ca, cb),
+            junctions: DisjointSet::new(junction_count),
         }
     }
 
-    fn new_circuit(&mut self, ja: usize, jb: usize) {
-        let id = self.next_circuit_id;
-        self.next_circuit_id += 1;
-        self.map.insert(ja, id);
-        self.map.insert(jb, id);
-        self.sizes.insert(id, 2);
+    /// The number of circuits, including junction boxes that aren't connected to anything.
+    pub fn count(&self) -> usize {
+        return self.junctions.count();
     }
 
-    fn add_to_circuit(&mut self, c: usize, j: usize) {
-        self.map.insert(j, c);
-        if let Some(s) = self.sizes.get_mut(&c) {
-            *s += 1;
-        } else {
-            panic!("Missing circuit {} from `sizes`!", c);
-        }
+    pub fn get_sizes_sorted(&self, n: usize) -> impl Iterator<Item = usize> {
+        return self.junctions.largest(n).into_iter();
     }
 
-    fn merge_circuits(&mut self, ca: usize, cb: usize) {
-        if ca == cb {
-            // * `ca` and `cb` are the same; no need to merge!
-            return;
-        }
-
-        // * Merge `cb` into `ca`.
-        for c in self.map.values_mut() {
-            if *c == cb {
-                *c = ca;
-            }
-        }
-        let count = self.sizes.remove(&cb).unwrap();
-        if let Some(s) = self.sizes.get_mut(&ca) {
-            *s += count;
-        } else {
-            panic!("Missing circuit {} from `sizes`!", cb);
-        }
-
+    /// Connect two junction boxes, returning `false` if they were already in the same circuit.
+    pub fn connect(&mut self, ja: usize, jb: usize) -> bool {
+        return self.junctions.union(ja, jb);
     }
 }
 
-fn load_positions() -> Vec<Pos> {
+pub(super) fn load_positions(input: &str) -> Vec<Pos> {
     let mut positions = Vec::new();
-    for l in INPUT.lines() {
+    for l in input.lines() {
         let s = l.split(',').collect::<Vec<_>>();
         let x = s[0].parse().unwrap();
         let y = s[1].parse().unwrap();
@@ -137,29 +41,22 @@ fn load_positions() -> Vec<Pos> {
     return positions;
 }
 
-pub fn solve_pt1() -> u64 {
+pub fn solve_pt1(input: &str) -> u64 {
     // * How many connections should we make?
     // ? 10 for the example input, 1000 for the actual input.
-    const CONNECTION_COUNT: usize = 1000;
+    return largest_circuits(input, 1000);
+}
+
+fn largest_circuits(input: &str, connection_count: usize) -> u64 {
     // * How many of the largest circuits should we multiply together to get the solution?
     const LARGEST_COUNT: usize = 3;
 
-    let positions = load_positions();
-    let len = positions.len();
-    
-    let mut pairs = Vec::with_capacity(len);
-    let mut circuits = Circuits::new(CONNECTION_COUNT);
-    
-    for ja in 0..len {
-        for jb in (ja + 1)..len {
-            pairs.push((ja, jb));
-        }
-    }
-
-    // * Sort the pairs from least distance to most distance.
-    pairs.sort_by_cached_key(|(a, b)| Pos::dist_squared(positions[*a], positions[*b]));
+    let positions = load_positions(input);
+    let tree = KdTree::new(&positions);
+    let mut circuits = Circuits::new(positions.len());
 
-    for (ja, jb) in pairs.into_iter().take(CONNECTION_COUNT) {
+    // * The pairs are found from least distance to most distance, so only the closest ones are ever checked.
+    for (_, ja, jb) in tree.pairs().take(connection_count) {
         circuits.connect(ja, jb);
     }
 
@@ -171,28 +68,21 @@ pub fn solve_pt1() -> u64 {
 
 }
 
-pub fn solve_pt2() -> u64 {
-    let positions = load_positions();
-    let len = positions.len();
-    
-    let mut pairs = Vec::with_capacity(len);
-    let mut circuits = Circuits::new(len);
-    
-    for ja in 0..len {
-        for jb in (ja + 1)..len {
-            pairs.push((ja, jb));
-        }
-    }
-
-    // * Sort the pairs from least distance to most distance.
-    pairs.sort_by_cached_key(|(a, b)| Pos::dist_squared(positions[*a], positions[*b]));
+pub fn solve_pt2(input: &str) -> u64 {
+    let positions = load_positions(input);
+    let tree = KdTree::new(&positions);
+    let mut circuits = Circuits::new(positions.len());
 
     let mut last_connection = None;
 
-    for (ja, jb) in pairs {
-        if !circuits.same_circuit(ja, jb) {
-            circuits.connect(ja, jb);
+    // * The pairs are found from least distance to most distance.
+    for (_, ja, jb) in tree.pairs() {
+        if circuits.connect(ja, jb) {
             last_connection = Some((ja, jb));
+            if circuits.count() == 1 {
+                // * Every junction box is now in the same circuit.
+                break;
+            }
         }
     }
 
@@ -203,4 +93,41 @@ pub fn solve_pt2() -> u64 {
     } else {
         unreachable!("No connections were made!");
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(largest_circuits(EXAMPLE, 10), 40);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(solve_pt2(EXAMPLE), 25272);
+    }
+}
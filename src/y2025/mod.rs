@@ -1,3 +1,5 @@
+use crate::{day, solution::Day};
+
 pub mod d1;
 pub mod d2;
 pub mod d3;
@@ -6,3 +8,15 @@ pub mod d5;
 pub mod d6;
 pub mod d7;
 pub mod d8;
+
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+    day!(2025, 1, "Secret Entrance", d1::solve_pt1, d1::solve_pt2),
+    day!(2025, 2, "Gift Shop", d2::solve_pt1, d2::solve_pt2; parse = d2::load_ranges),
+    day!(2025, 3, "Lobby", d3::solve_pt1, d3::solve_pt2),
+    day!(2025, 4, "Printing Department", d4::solve_pt1, d4::solve_pt2; parse = d4::load_grid),
+    day!(2025, 5, "Cafeteria", d5::solve_pt1, d5::solve_pt2; parse = d5::load_input),
+    day!(2025, 6, "Trash Compactor", d6::solve_pt1, d6::solve_pt2; parse = d6::load_worksheet),
+    day!(2025, 7, "Laboratories", d7::solve_pt1, d7::solve_pt2; parse = d7::load_diagram),
+    day!(2025, 8, "Playground", d8::solve_pt1, d8::solve_pt2; parse = d8::load_positions),
+];